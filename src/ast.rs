@@ -11,15 +11,16 @@ pub enum Statement {
     VariableDeclaration {
         token: Token,
         name: String,
-        type_name: Option<String>,
+        type_name: Option<TypeAnnotation>,
         value: Option<Expression>,
     },
     FunctionDeclaration {
         token: Token,
         name: String,
-        parameters: Vec<(String, String)>, // (name, type)
+        parameters: Vec<(String, TypeAnnotation)>, // (name, type)
         body: Vec<Statement>,
-        return_type: Option<String>,
+        return_type: Option<TypeAnnotation>,
+        cleanup: Option<Vec<Statement>>,
     },
     ReturnStatement {
         token: Token,
@@ -51,7 +52,14 @@ pub enum Statement {
     },
     ForEachStatement {
         token: Token,
-        element_variable: String,
+        index_variable: Option<String>,
+        element_variable: LoopVariable,
+        iterator: Expression,
+        body: Box<Statement>,
+    },
+    ForInStatement {
+        token: Token,
+        key_variable: String,
         iterator: Expression,
         body: Box<Statement>,
     },
@@ -69,12 +77,23 @@ pub enum Statement {
     ObjectDeclaration {
         token: Token,
         name: String,
-        properties: Vec<(String, Expression)>,
+        properties: Vec<ObjectProperty>,
+    },
+    UnionDeclaration {
+        token: Token,
+        name: String,
+        members: Vec<String>,
+    },
+    StructDeclaration {
+        token: Token,
+        name: String,
+        fields: Vec<(String, String)>, // (name, type)
     },
     ClassDeclaration {
         token: Token,
         name: String,
         superclass: Option<String>,
+        mixins: Vec<String>,
         interfaces: Vec<String>,
         members: Vec<ClassMember>,
     },
@@ -95,9 +114,13 @@ pub enum Statement {
     SwitchStatement {
         token: Token,
         expression: Expression,
-        cases: Vec<(Expression, Vec<Statement>)>,
+        cases: Vec<(Vec<Expression>, Vec<Statement>)>, // (case values, body)
         default: Option<Vec<Statement>>,
     },
+    CancelStatement {
+        token: Token,
+        handle: Expression,
+    },
     BlockStatement(Vec<Statement>),
 }
 
@@ -113,11 +136,22 @@ pub enum ClassMember {
     Method {
         token: Token,
         name: String,
-        parameters: Vec<(String, String)>, // (name, type)
+        parameters: Vec<(String, TypeAnnotation)>, // (name, type)
         body: Vec<Statement>,
-        return_type: Option<String>,
+        return_type: Option<TypeAnnotation>,
         visibility: Visibility,
         is_static: bool,
+        is_override: bool,
+    },
+    AbstractMethod {
+        token: Token,
+        name: String,
+        parameters: Vec<(String, TypeAnnotation)>, // (name, type)
+        return_type: Option<TypeAnnotation>,
+    },
+    StaticInitializer {
+        token: Token,
+        body: Vec<Statement>,
     },
 }
 
@@ -125,19 +159,45 @@ pub enum InterfaceMember {
     Method {
         token: Token,
         name: String,
-        parameters: Vec<(String, String)>, // (name, type)
-        return_type: Option<String>,
+        parameters: Vec<(String, TypeAnnotation)>, // (name, type)
+        return_type: Option<TypeAnnotation>,
+    },
+    Constant {
+        token: Token,
+        name: String,
+        type_name: TypeAnnotation,
+        value: Expression,
     },
 }
 
 pub enum ImportSpecifier {
     Named(String),
-    Default(String),
+    Default {
+        name: String,
+        alias: Option<String>,
+    },
 }
 
 pub enum ExportSpecifier {
     Named(String),
-    Default,
+    Default(Box<Statement>), // expression statement or declaration
+}
+
+pub enum ObjectProperty {
+    Named(String, Expression),        // key: value
+    Computed(Expression, Expression), // [keyExpr]: value
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LoopVariable {
+    Simple(String, Option<String>), // (name, type)
+    Object(Vec<(String, String)>), // (name, type) for each destructured property
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TypeAnnotation {
+    Named(String),
+    Union(Vec<String>), // int | float
 }
 
 pub enum Visibility {
@@ -184,6 +244,13 @@ pub enum Expression {
         array: Box<Expression>,
         index: Box<Expression>,
     },
+    SliceAccess {
+        token: Token,
+        array: Box<Expression>,
+        start: Option<Box<Expression>>,
+        end: Option<Box<Expression>>,
+        step: Option<Box<Expression>>,
+    },
     MemberAccess {
         token: Token,
         object: Box<Expression>,
@@ -197,7 +264,7 @@ pub enum Expression {
     },
     DictLiteral {
         token: Token,
-        pairs: Vec<(Expression, Expression)>, // Key-value pairs
+        pairs: Vec<ObjectProperty>, // Key-value pairs
     },
     NewExpression {
         token: Token,
@@ -207,4 +274,29 @@ pub enum Expression {
     This {
         token: Token,
     },
+    SuperCall {
+        token: Token,
+        arguments: Vec<Expression>,
+    },
+    Clone {
+        token: Token,
+        value: Box<Expression>,
+    },
+    Defer {
+        token: Token,
+        expression: Box<Expression>,
+    },
+    Delete {
+        token: Token,
+        target: Box<Expression>,
+    },
+    Freeze {
+        token: Token,
+        value: Box<Expression>,
+    },
+    NamedArgument {
+        token: Token,
+        name: String,
+        value: Box<Expression>,
+    },
 }
\ No newline at end of file
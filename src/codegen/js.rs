@@ -0,0 +1,626 @@
+use crate::ast::{
+    ClassMember, ExportSpecifier, Expression, ImportSpecifier, InterfaceMember, LiteralValue, LoopVariable, ObjectProperty,
+    Statement, TypeAnnotation,
+};
+use crate::codegen::{quote_string, IndentLevel};
+use crate::token::TokenType;
+
+const PRECEDENCE_ASSIGNMENT: u8 = 2;
+const PRECEDENCE_LOGICAL_OR: u8 = 3;
+const PRECEDENCE_LOGICAL_AND: u8 = 4;
+const PRECEDENCE_EQUALITY: u8 = 8;
+const PRECEDENCE_RELATIONAL: u8 = 9;
+const PRECEDENCE_ADDITIVE: u8 = 11;
+const PRECEDENCE_MULTIPLICATIVE: u8 = 12;
+const PRECEDENCE_UNARY: u8 = 14;
+const PRECEDENCE_MEMBER: u8 = 17;
+const PRECEDENCE_PRIMARY: u8 = 20;
+
+// Transpiles a parsed program to JavaScript source. Types only survive as
+// JSDoc comments, and interfaces become comment blocks.
+pub struct JsEmitter {
+    output: String,
+    indent: IndentLevel,
+    errors: Vec<String>,
+}
+
+impl JsEmitter {
+    pub fn new() -> Self {
+        JsEmitter {
+            output: String::new(),
+            indent: IndentLevel::new(2),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn get_errors(&self) -> &Vec<String> {
+        &self.errors
+    }
+
+    pub fn emit(&mut self, program: &[Statement]) -> String {
+        for statement in program {
+            self.emit_statement(statement);
+        }
+        std::mem::take(&mut self.output)
+    }
+
+    fn emit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::VariableDeclaration { token, name, value, .. } => {
+                let keyword = if token.token_type == TokenType::ConstKeyword { "const" } else { "let" };
+                match value {
+                    Some(value) => {
+                        let value = self.operand(value, PRECEDENCE_ASSIGNMENT);
+                        self.line(&format!("{} {} = {};", keyword, name, value));
+                    }
+                    None => self.line(&format!("{} {};", keyword, name)),
+                }
+            }
+            Statement::FunctionDeclaration { name, parameters, body, return_type, cleanup, .. } => {
+                self.emit_jsdoc(parameters, return_type.as_ref());
+                self.line(&format!("function {}({}) {{", name, parameter_names(parameters)));
+                self.indented(|emitter| emitter.emit_function_body(body, cleanup.as_deref()));
+                self.line("}");
+            }
+            Statement::ReturnStatement { value, .. } => match value {
+                Some(value) => {
+                    let value = self.expression(value);
+                    self.line(&format!("return {};", value));
+                }
+                None => self.line("return;"),
+            },
+            Statement::Expression(expression) => {
+                let expression = self.expression(expression);
+                // A leading '{' would be read as a block
+                if expression.starts_with('{') {
+                    self.line(&format!("({});", expression));
+                } else {
+                    self.line(&format!("{};", expression));
+                }
+            }
+            Statement::IfStatement { condition, then_branch, else_branch, .. } => {
+                self.emit_if("if", condition, then_branch, else_branch.as_deref());
+                self.line("}");
+            }
+            Statement::DoWhileStatement { body, condition, .. } => {
+                self.line("do {");
+                self.emit_body(body);
+                let condition = self.expression(condition);
+                self.line(&format!("}} while ({});", condition));
+            }
+            Statement::WhileStatement { condition, body, .. } => {
+                let condition = self.expression(condition);
+                self.line(&format!("while ({}) {{", condition));
+                self.emit_body(body);
+                self.line("}");
+            }
+            Statement::ForStatement { initializer, condition, increment, body, .. } => {
+                let initializer = match initializer.as_deref() {
+                    Some(Statement::VariableDeclaration { name, value: Some(value), .. }) => {
+                        format!("let {} = {}", name, self.operand(value, PRECEDENCE_ASSIGNMENT))
+                    }
+                    Some(Statement::Expression(expression)) => self.expression(expression),
+                    Some(_) => {
+                        self.errors.push("Unsupported for loop initializer in js target".to_string());
+                        String::new()
+                    }
+                    None => String::new(),
+                };
+                let condition = condition.as_ref().map(|condition| self.expression(condition)).unwrap_or_default();
+                let increment = increment.as_ref().map(|increment| self.expression(increment)).unwrap_or_default();
+                self.line(&format!("for ({}; {}; {}) {{", initializer, condition, increment));
+                self.emit_body(body);
+                self.line("}");
+            }
+            Statement::ForEachStatement { index_variable, element_variable, iterator, body, .. } => {
+                let element_variable = match element_variable {
+                    LoopVariable::Simple(name, _) => name.clone(),
+                    LoopVariable::Object(properties) => {
+                        let names: Vec<&str> = properties.iter().map(|(name, _)| name.as_str()).collect();
+                        format!("{{ {} }}", names.join(", "))
+                    }
+                };
+                match index_variable {
+                    Some(index_variable) => {
+                        let iterator = self.operand(iterator, PRECEDENCE_MEMBER);
+                        self.line(&format!(
+                            "for (const [{}, {}] of {}.entries()) {{",
+                            index_variable, element_variable, iterator
+                        ));
+                    }
+                    None => {
+                        let iterator = self.expression(iterator);
+                        self.line(&format!("for (const {} of {}) {{", element_variable, iterator));
+                    }
+                }
+                self.emit_body(body);
+                self.line("}");
+            }
+            Statement::ForInStatement { key_variable, iterator, body, .. } => {
+                let iterator = self.expression(iterator);
+                self.line(&format!("for (const {} in {}) {{", key_variable, iterator));
+                self.emit_body(body);
+                self.line("}");
+            }
+            Statement::BreakStatement { .. } => self.line("break;"),
+            Statement::ContinueStatement { .. } => self.line("continue;"),
+            Statement::EnumDeclaration { name, variants, .. } => {
+                let variants: Vec<String> =
+                    variants.iter().enumerate().map(|(index, variant)| format!("{}: {}", variant, index)).collect();
+                self.line(&format!("const {} = Object.freeze({{ {} }});", name, variants.join(", ")));
+            }
+            Statement::ObjectDeclaration { name, properties, .. } => {
+                let object = self.object_literal(properties);
+                self.line(&format!("const {} = {};", name, object));
+            }
+            Statement::UnionDeclaration { name, members, .. } => {
+                self.line(&format!("// union {} = {}", name, members.join(" | ")));
+            }
+            Statement::StructDeclaration { name, fields, .. } => {
+                let field_names: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+                self.line(&format!("class {} {{", name));
+                self.indented(|emitter| {
+                    emitter.line(&format!("constructor({}) {{", field_names.join(", ")));
+                    emitter.indented(|emitter| {
+                        for field in &field_names {
+                            emitter.line(&format!("this.{} = {};", field, field));
+                        }
+                    });
+                    emitter.line("}");
+                });
+                self.line("}");
+            }
+            Statement::ClassDeclaration { name, superclass, mixins, interfaces, members, .. } => {
+                if !mixins.is_empty() {
+                    self.errors.push(format!("Class '{}': mixins are not supported by the js target", name));
+                }
+                if !interfaces.is_empty() {
+                    self.line(&format!("// implements {}", interfaces.join(", ")));
+                }
+                match superclass {
+                    Some(superclass) => self.line(&format!("class {} extends {} {{", name, superclass)),
+                    None => self.line(&format!("class {} {{", name)),
+                }
+                self.indented(|emitter| {
+                    for member in members {
+                        emitter.emit_class_member(member);
+                    }
+                });
+                self.line("}");
+            }
+            Statement::InterfaceDeclaration { name, members, .. } => {
+                // JavaScript has no interfaces, so keep the shape as documentation
+                self.line("/*");
+                self.line(&format!(" * interface {} {{", name));
+                for member in members {
+                    match member {
+                        InterfaceMember::Method { name, parameters, return_type, .. } => {
+                            let parameters: Vec<String> = parameters
+                                .iter()
+                                .map(|(name, type_annotation)| format!("{}: {}", name, js_type(type_annotation)))
+                                .collect();
+                            let return_type = return_type.as_ref().map(js_type).unwrap_or_else(|| "void".to_string());
+                            self.line(&format!(" *   {}({}): {};", name, parameters.join(", "), return_type));
+                        }
+                        InterfaceMember::Constant { name, type_name, value, .. } => {
+                            let value = self.expression(value);
+                            self.line(&format!(" *   const {}: {} = {};", name, js_type(type_name), value));
+                        }
+                    }
+                }
+                self.line(" * }");
+                self.line(" */");
+            }
+            Statement::ImportDeclaration { path, imports, .. } => {
+                let mut named = Vec::new();
+                for import in imports {
+                    match import {
+                        ImportSpecifier::Named(name) => named.push(name.as_str()),
+                        ImportSpecifier::Default { name, alias } => {
+                            let binding = alias.as_ref().unwrap_or(name);
+                            self.line(&format!("import {} from {};", binding, quote_string(path)));
+                        }
+                    }
+                }
+                if !named.is_empty() {
+                    self.line(&format!("import {{ {} }} from {};", named.join(", "), quote_string(path)));
+                }
+            }
+            Statement::ExportDeclaration { specifiers, .. } => {
+                let mut named = Vec::new();
+                for specifier in specifiers {
+                    match specifier {
+                        ExportSpecifier::Named(name) => named.push(name.as_str()),
+                        ExportSpecifier::Default(statement) => match &**statement {
+                            Statement::Expression(expression) => {
+                                let expression = self.operand(expression, PRECEDENCE_ASSIGNMENT);
+                                self.line(&format!("export default {};", expression));
+                            }
+                            // Declarations keep their JSDoc, so export the name afterwards
+                            Statement::FunctionDeclaration { name, .. } | Statement::ClassDeclaration { name, .. } => {
+                                self.emit_statement(statement);
+                                self.line(&format!("export default {};", name));
+                            }
+                            _ => self.errors.push(
+                                "Only expressions, functions and classes can be default exports in the js target".to_string(),
+                            ),
+                        },
+                    }
+                }
+                if !named.is_empty() {
+                    self.line(&format!("export {{ {} }};", named.join(", ")));
+                }
+            }
+            Statement::SwitchStatement { expression, cases, default, .. } => {
+                let expression = self.expression(expression);
+                self.line(&format!("switch ({}) {{", expression));
+                self.indented(|emitter| {
+                    for (values, body) in cases {
+                        for value in values {
+                            let value = emitter.expression(value);
+                            emitter.line(&format!("case {}:", value));
+                        }
+                        emitter.indented(|emitter| emitter.emit_statements(body));
+                    }
+                    if let Some(body) = default {
+                        emitter.line("default:");
+                        emitter.indented(|emitter| emitter.emit_statements(body));
+                    }
+                });
+                self.line("}");
+            }
+            Statement::CancelStatement { .. } => {
+                self.errors.push("Cancel statements are not supported by the js target".to_string());
+            }
+            Statement::BlockStatement(statements) => {
+                self.line("{");
+                self.indented(|emitter| emitter.emit_statements(statements));
+                self.line("}");
+            }
+        }
+    }
+
+    fn emit_class_member(&mut self, member: &ClassMember) {
+        match member {
+            ClassMember::Field { name, value, is_static, .. } => {
+                let prefix = if *is_static { "static " } else { "" };
+                match value {
+                    Some(value) => {
+                        let value = self.operand(value, PRECEDENCE_ASSIGNMENT);
+                        self.line(&format!("{}{} = {};", prefix, name, value));
+                    }
+                    None => self.line(&format!("{}{};", prefix, name)),
+                }
+            }
+            ClassMember::Method { name, parameters, body, return_type, is_static, .. } => {
+                let prefix = if *is_static { "static " } else { "" };
+                self.emit_jsdoc(parameters, return_type.as_ref());
+                self.line(&format!("{}{}({}) {{", prefix, name, parameter_names(parameters)));
+                self.indented(|emitter| emitter.emit_statements(body));
+                self.line("}");
+            }
+            ClassMember::AbstractMethod { name, parameters, return_type, .. } => {
+                self.emit_jsdoc(parameters, return_type.as_ref());
+                self.line(&format!("{}({}) {{", name, parameter_names(parameters)));
+                self.indented(|emitter| {
+                    emitter.line(&format!("throw new Error({});", quote_string(&format!("{} is abstract", name))))
+                });
+                self.line("}");
+            }
+            ClassMember::StaticInitializer { body, .. } => {
+                self.line("static {");
+                self.indented(|emitter| emitter.emit_statements(body));
+                self.line("}");
+            }
+        }
+    }
+
+    fn emit_function_body(&mut self, body: &[Statement], cleanup: Option<&[Statement]>) {
+        match cleanup {
+            Some(cleanup) => {
+                self.line("try {");
+                self.indented(|emitter| emitter.emit_statements(body));
+                self.line("} finally {");
+                self.indented(|emitter| emitter.emit_statements(cleanup));
+                self.line("}");
+            }
+            None => self.emit_statements(body),
+        }
+    }
+
+    // Emits everything up to, but not including, the closing brace
+    fn emit_if(&mut self, keyword: &str, condition: &Expression, then_branch: &Statement, else_branch: Option<&Statement>) {
+        let condition = self.expression(condition);
+        self.line(&format!("{} ({}) {{", keyword, condition));
+        self.emit_body(then_branch);
+
+        match else_branch {
+            Some(Statement::IfStatement { condition, then_branch, else_branch, .. }) => {
+                self.emit_if("} else if", condition, then_branch, else_branch.as_deref());
+            }
+            Some(Statement::BlockStatement(statements)) if is_else_if(statements) => {
+                if let Statement::IfStatement { condition, then_branch, else_branch, .. } = &statements[0] {
+                    self.emit_if("} else if", condition, then_branch, else_branch.as_deref());
+                }
+            }
+            Some(else_branch) => {
+                self.line("} else {");
+                self.emit_body(else_branch);
+            }
+            None => {}
+        }
+    }
+
+    // Emits the statements of a loop or branch body, unwrapping blocks
+    fn emit_body(&mut self, body: &Statement) {
+        self.indented(|emitter| match body {
+            Statement::BlockStatement(statements) => emitter.emit_statements(statements),
+            statement => emitter.emit_statement(statement),
+        });
+    }
+
+    fn emit_statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.emit_statement(statement);
+        }
+    }
+
+    fn emit_jsdoc(&mut self, parameters: &[(String, TypeAnnotation)], return_type: Option<&TypeAnnotation>) {
+        if parameters.is_empty() && return_type.is_none() {
+            return;
+        }
+        self.line("/**");
+        for (name, type_annotation) in parameters {
+            self.line(&format!(" * @param {{{}}} {}", js_type(type_annotation), name));
+        }
+        if let Some(return_type) = return_type {
+            self.line(&format!(" * @returns {{{}}}", js_type(return_type)));
+        }
+        self.line(" */");
+    }
+
+    fn expression(&mut self, expression: &Expression) -> String {
+        self.emit_expression(expression).0
+    }
+
+    // Emits an expression, parenthesised if it binds looser than `precedence`
+    fn operand(&mut self, expression: &Expression, precedence: u8) -> String {
+        let (text, expression_precedence) = self.emit_expression(expression);
+        if expression_precedence < precedence {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[Expression]) -> String {
+        let arguments: Vec<String> =
+            arguments.iter().map(|argument| self.operand(argument, PRECEDENCE_ASSIGNMENT)).collect();
+        arguments.join(", ")
+    }
+
+    // Returns the JavaScript text of an expression and its precedence
+    fn emit_expression(&mut self, expression: &Expression) -> (String, u8) {
+        match expression {
+            Expression::Literal { value, .. } => {
+                let text = match value {
+                    LiteralValue::Int(value) => value.to_string(),
+                    LiteralValue::Float(value) => value.to_string(),
+                    LiteralValue::String(value) => quote_string(value),
+                    LiteralValue::Bool(value) => value.to_string(),
+                };
+                (text, PRECEDENCE_PRIMARY)
+            }
+            Expression::Identifier { name, .. } => (name.clone(), PRECEDENCE_PRIMARY),
+            Expression::BinaryOperation { left, operator, right, .. } => {
+                let (operator_text, precedence) = match binary_operator(operator) {
+                    Some(operator) => operator,
+                    None => {
+                        self.errors.push(format!("Unsupported binary operator {:?} in js target", operator));
+                        return (String::new(), PRECEDENCE_PRIMARY);
+                    }
+                };
+                let left = self.operand(left, precedence);
+                let right = self.operand(right, precedence + 1);
+                (format!("{} {} {}", left, operator_text, right), precedence)
+            }
+            Expression::UnaryOperation { operator, operand, .. } => {
+                let operator_text = match operator {
+                    TokenType::Minus => "-",
+                    TokenType::LogicalNot => "!",
+                    TokenType::PlusPlus => "++",
+                    TokenType::MinusMinus => "--",
+                    _ => {
+                        self.errors.push(format!("Unsupported unary operator {:?} in js target", operator));
+                        return (String::new(), PRECEDENCE_PRIMARY);
+                    }
+                };
+                let operand = self.operand(operand, PRECEDENCE_UNARY);
+                // Keep `- -x` from turning into a decrement
+                let separator = if operand.starts_with(operator_text) { " " } else { "" };
+                (format!("{}{}{}", operator_text, separator, operand), PRECEDENCE_UNARY)
+            }
+            Expression::Assignment { token, left, right } => {
+                let operator_text = match assignment_operator(&token.token_type) {
+                    Some(operator_text) => operator_text,
+                    None => {
+                        self.errors.push(format!("Unsupported assignment operator {:?} in js target", token.token_type));
+                        return (String::new(), PRECEDENCE_PRIMARY);
+                    }
+                };
+                let left = self.operand(left, PRECEDENCE_MEMBER);
+                let right = self.operand(right, PRECEDENCE_ASSIGNMENT);
+                (format!("{} {} {}", left, operator_text, right), PRECEDENCE_ASSIGNMENT)
+            }
+            Expression::FunctionCall { callee, arguments, .. } => {
+                let callee = self.operand(callee, PRECEDENCE_MEMBER);
+                let arguments = self.arguments(arguments);
+                (format!("{}({})", callee, arguments), PRECEDENCE_MEMBER)
+            }
+            Expression::ArrayLiteral { elements, .. } => {
+                let elements = self.arguments(elements);
+                (format!("[{}]", elements), PRECEDENCE_PRIMARY)
+            }
+            Expression::IndexAccess { array, index, .. } => {
+                let array = self.operand(array, PRECEDENCE_MEMBER);
+                let index = self.expression(index);
+                (format!("{}[{}]", array, index), PRECEDENCE_MEMBER)
+            }
+            Expression::SliceAccess { array, start, end, step, .. } => {
+                if step.is_some() {
+                    self.errors.push("Slice steps are not supported by the js target".to_string());
+                }
+                let array = self.operand(array, PRECEDENCE_MEMBER);
+                let start = start.as_ref().map(|start| self.operand(start, PRECEDENCE_ASSIGNMENT));
+                let end = end.as_ref().map(|end| self.operand(end, PRECEDENCE_ASSIGNMENT));
+                let arguments = match (start, end) {
+                    (start, Some(end)) => format!("{}, {}", start.unwrap_or_else(|| "0".to_string()), end),
+                    (Some(start), None) => start,
+                    (None, None) => String::new(),
+                };
+                (format!("{}.slice({})", array, arguments), PRECEDENCE_MEMBER)
+            }
+            Expression::MemberAccess { object, member, .. } => {
+                let object = self.operand(object, PRECEDENCE_MEMBER);
+                (format!("{}.{}", object, member), PRECEDENCE_MEMBER)
+            }
+            Expression::Ternary { condition, then_expression, else_expression, .. } => {
+                let condition = self.operand(condition, PRECEDENCE_LOGICAL_OR);
+                let then_expression = self.operand(then_expression, PRECEDENCE_ASSIGNMENT);
+                let else_expression = self.operand(else_expression, PRECEDENCE_ASSIGNMENT);
+                (format!("{} ? {} : {}", condition, then_expression, else_expression), PRECEDENCE_ASSIGNMENT)
+            }
+            Expression::DictLiteral { pairs, .. } => (self.object_literal(pairs), PRECEDENCE_PRIMARY),
+            Expression::NewExpression { class_name, arguments, .. } => {
+                let arguments = self.arguments(arguments);
+                (format!("new {}({})", class_name, arguments), PRECEDENCE_MEMBER)
+            }
+            Expression::This { .. } => ("this".to_string(), PRECEDENCE_PRIMARY),
+            Expression::SuperCall { arguments, .. } => {
+                let arguments = self.arguments(arguments);
+                (format!("super({})", arguments), PRECEDENCE_MEMBER)
+            }
+            Expression::Clone { value, .. } => {
+                let value = self.operand(value, PRECEDENCE_ASSIGNMENT);
+                (format!("structuredClone({})", value), PRECEDENCE_MEMBER)
+            }
+            Expression::Defer { .. } => {
+                self.errors.push("Defer expressions are not supported by the js target".to_string());
+                (String::new(), PRECEDENCE_PRIMARY)
+            }
+            Expression::Freeze { value, .. } => {
+                let value = self.operand(value, PRECEDENCE_ASSIGNMENT);
+                (format!("Object.freeze({})", value), PRECEDENCE_MEMBER)
+            }
+            Expression::Delete { target, .. } => {
+                let target = self.operand(target, PRECEDENCE_UNARY);
+                (format!("delete {}", target), PRECEDENCE_UNARY)
+            }
+            Expression::NamedArgument { name, .. } => {
+                self.errors.push(format!("Named argument '{}' is not supported by the js target", name));
+                (String::new(), PRECEDENCE_PRIMARY)
+            }
+        }
+    }
+
+    fn object_literal(&mut self, properties: &[ObjectProperty]) -> String {
+        if properties.is_empty() {
+            return "{}".to_string();
+        }
+        let properties: Vec<String> = properties
+            .iter()
+            .map(|property| match property {
+                ObjectProperty::Named(key, value) => {
+                    format!("{}: {}", property_key(key), self.operand(value, PRECEDENCE_ASSIGNMENT))
+                }
+                ObjectProperty::Computed(key, value) => {
+                    format!("[{}]: {}", self.expression(key), self.operand(value, PRECEDENCE_ASSIGNMENT))
+                }
+            })
+            .collect();
+        format!("{{ {} }}", properties.join(", "))
+    }
+
+    fn indented(&mut self, emit: impl FnOnce(&mut Self)) {
+        self.indent.increase();
+        emit(self);
+        self.indent.decrease();
+    }
+
+    fn line(&mut self, text: &str) {
+        self.output.push_str(&self.indent.prefix());
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+}
+
+fn is_else_if(statements: &[Statement]) -> bool {
+    statements.len() == 1 && matches!(statements[0], Statement::IfStatement { .. })
+}
+
+fn parameter_names(parameters: &[(String, TypeAnnotation)]) -> String {
+    let names: Vec<&str> = parameters.iter().map(|(name, _)| name.as_str()).collect();
+    names.join(", ")
+}
+
+fn js_type(type_annotation: &TypeAnnotation) -> String {
+    let names = match type_annotation {
+        TypeAnnotation::Named(name) => vec![name.as_str()],
+        TypeAnnotation::Union(names) => names.iter().map(String::as_str).collect(),
+    };
+
+    let mut types: Vec<&str> = Vec::new();
+    for name in names {
+        let js_name = match name {
+            "int" | "float" => "number",
+            "bool" => "boolean",
+            other => other,
+        };
+        if !types.contains(&js_name) {
+            types.push(js_name);
+        }
+    }
+    types.join("|")
+}
+
+fn property_key(key: &str) -> String {
+    let mut characters = key.chars();
+    let is_identifier = characters.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && characters.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        key.to_string()
+    } else {
+        quote_string(key)
+    }
+}
+
+fn assignment_operator(operator: &TokenType) -> Option<&'static str> {
+    match operator {
+        TokenType::Equals => Some("="),
+        TokenType::PlusEquals => Some("+="),
+        TokenType::MinusEquals => Some("-="),
+        TokenType::StarEquals => Some("*="),
+        TokenType::SlashEquals => Some("/="),
+        TokenType::PercentEquals => Some("%="),
+        _ => None,
+    }
+}
+
+fn binary_operator(operator: &TokenType) -> Option<(&'static str, u8)> {
+    match operator {
+        TokenType::LogicalOr => Some(("||", PRECEDENCE_LOGICAL_OR)),
+        TokenType::LogicalAnd => Some(("&&", PRECEDENCE_LOGICAL_AND)),
+        TokenType::EqualsEquals => Some(("===", PRECEDENCE_EQUALITY)),
+        TokenType::NotEquals => Some(("!==", PRECEDENCE_EQUALITY)),
+        TokenType::LessThan => Some(("<", PRECEDENCE_RELATIONAL)),
+        TokenType::GreaterThan => Some((">", PRECEDENCE_RELATIONAL)),
+        TokenType::LessThanEquals => Some(("<=", PRECEDENCE_RELATIONAL)),
+        TokenType::GreaterThanEquals => Some((">=", PRECEDENCE_RELATIONAL)),
+        TokenType::Plus => Some(("+", PRECEDENCE_ADDITIVE)),
+        TokenType::Minus => Some(("-", PRECEDENCE_ADDITIVE)),
+        TokenType::Star => Some(("*", PRECEDENCE_MULTIPLICATIVE)),
+        TokenType::Slash => Some(("/", PRECEDENCE_MULTIPLICATIVE)),
+        TokenType::Percent => Some(("%", PRECEDENCE_MULTIPLICATIVE)),
+        _ => None,
+    }
+}
\ No newline at end of file
@@ -0,0 +1,378 @@
+use crate::ast::{
+    ClassMember, ExportSpecifier, Expression, InterfaceMember, LiteralValue, LoopVariable, Statement, TypeAnnotation,
+    Visibility,
+};
+use crate::codegen::js::JsEmitter;
+use crate::test_ast::*;
+use crate::token::TokenType;
+
+fn emit(program: &[Statement]) -> String {
+    let mut emitter = JsEmitter::new();
+    let source = emitter.emit(program);
+    assert!(emitter.get_errors().is_empty(), "unexpected errors: {:?}", emitter.get_errors());
+    source
+}
+
+fn string(value: &str) -> Expression {
+    Expression::Literal { token: token(TokenType::String(value.to_string())), value: LiteralValue::String(value.to_string()) }
+}
+
+fn member(object: Expression, member: &str) -> Expression {
+    Expression::MemberAccess { token: token(TokenType::Dot), object: Box::new(object), member: member.to_string() }
+}
+
+#[test]
+fn test_js_variable_declarations() {
+    let program = vec![
+        var("x", "int", binary(binary(ident("a"), TokenType::Plus, ident("b")), TokenType::Star, ident("c"))),
+        Statement::VariableDeclaration {
+            token: token(TokenType::ConstKeyword),
+            name: "greeting".to_string(),
+            type_name: Some(TypeAnnotation::Named("string".to_string())),
+            value: Some(string("say \"hi\"\n")),
+        },
+        Statement::VariableDeclaration {
+            token: token(TokenType::BoolKeyword),
+            name: "done".to_string(),
+            type_name: Some(TypeAnnotation::Named("bool".to_string())),
+            value: None,
+        },
+        Statement::Expression(assign("x", binary(ident("x"), TokenType::Minus, binary(ident("y"), TokenType::Minus, int(1))))),
+    ];
+
+    let expected = "let x = (a + b) * c;
+const greeting = \"say \\\"hi\\\"\\n\";
+let done;
+x = x - (y - 1);
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_compound_assignment() {
+    let program = vec![
+        Statement::Expression(compound_assign("x", TokenType::PlusEquals, int(1))),
+        Statement::Expression(compound_assign("x", TokenType::PercentEquals, binary(ident("y"), TokenType::Plus, int(2)))),
+    ];
+
+    assert_eq!(emit(&program), "x += 1;\nx %= y + 2;\n");
+}
+
+#[test]
+fn test_js_function_declaration() {
+    let program = vec![
+        function(
+            "add",
+            &[("a", "int"), ("b", "float")],
+            Some("float"),
+            vec![ret(binary(ident("a"), TokenType::Plus, ident("b")))],
+        ),
+        Statement::FunctionDeclaration {
+            token: token(TokenType::FunctionKeyword),
+            name: "run".to_string(),
+            parameters: vec![],
+            body: vec![Statement::Expression(call("work", vec![]))],
+            return_type: None,
+            cleanup: Some(vec![Statement::Expression(call("close", vec![string("file")]))]),
+        },
+    ];
+
+    let expected = "/**
+ * @param {number} a
+ * @param {number} b
+ * @returns {number}
+ */
+function add(a, b) {
+  return a + b;
+}
+function run() {
+  try {
+    work();
+  } finally {
+    close(\"file\");
+  }
+}
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_control_flow() {
+    let program = vec![
+        if_else(
+            binary(ident("n"), TokenType::LessThan, int(0)),
+            vec![ret(int(-1))],
+            Some(vec![if_else(
+                binary(ident("n"), TokenType::EqualsEquals, int(0)),
+                vec![ret(int(0))],
+                Some(vec![ret(int(1))]),
+            )]),
+        ),
+        while_loop(boolean(true), vec![Statement::BreakStatement { token: token(TokenType::BreakKeyword) }]),
+        Statement::DoWhileStatement {
+            token: token(TokenType::DoKeyword),
+            body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call("tick", vec![]))])),
+            condition: Expression::UnaryOperation {
+                token: token(TokenType::LogicalNot),
+                operator: TokenType::LogicalNot,
+                operand: Box::new(call("ready", vec![])),
+            },
+        },
+        Statement::ForStatement {
+            token: token(TokenType::ForKeyword),
+            initializer: Some(Box::new(var("i", "int", int(0)))),
+            condition: Some(binary(ident("i"), TokenType::LessThan, int(10))),
+            increment: Some(assign("i", binary(ident("i"), TokenType::Plus, int(1)))),
+            body: Box::new(Statement::BlockStatement(vec![Statement::ContinueStatement {
+                token: token(TokenType::ContinueKeyword),
+            }])),
+        },
+        Statement::ForEachStatement {
+            token: token(TokenType::ForKeyword),
+            index_variable: None,
+            element_variable: LoopVariable::Simple("item".to_string(), None),
+            iterator: ident("items"),
+            body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call(
+                "print",
+                vec![ident("item")],
+            ))])),
+        },
+        Statement::ForEachStatement {
+            token: token(TokenType::ForKeyword),
+            index_variable: Some("i".to_string()),
+            element_variable: LoopVariable::Simple("item".to_string(), None),
+            iterator: ident("items"),
+            body: Box::new(Statement::BlockStatement(vec![])),
+        },
+    ];
+
+    let expected = "if (n < 0) {
+  return -1;
+} else if (n === 0) {
+  return 0;
+} else {
+  return 1;
+}
+while (true) {
+  break;
+}
+do {
+  tick();
+} while (!ready());
+for (let i = 0; i < 10; i = i + 1) {
+  continue;
+}
+for (const item of items) {
+  print(item);
+}
+for (const [i, item] of items.entries()) {
+}
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_class_declaration() {
+    let this = || Expression::This { token: token(TokenType::ThisKeyword) };
+    let program = vec![Statement::ClassDeclaration {
+        token: token(TokenType::ClassKeyword),
+        name: "Circle".to_string(),
+        superclass: Some("Shape".to_string()),
+        mixins: vec![],
+        interfaces: vec!["Drawable".to_string()],
+        members: vec![
+            ClassMember::Field {
+                token: token(TokenType::Identifier("radius".to_string())),
+                name: "radius".to_string(),
+                type_name: Some("int".to_string()),
+                value: Some(int(1)),
+                visibility: Visibility::Private,
+                is_static: false,
+            },
+            ClassMember::Field {
+                token: token(TokenType::Identifier("count".to_string())),
+                name: "count".to_string(),
+                type_name: Some("int".to_string()),
+                value: None,
+                visibility: Visibility::Public,
+                is_static: true,
+            },
+            ClassMember::StaticInitializer {
+                token: token(TokenType::StaticKeyword),
+                body: vec![Statement::Expression(assign("count", int(0)))],
+            },
+            ClassMember::Method {
+                token: token(TokenType::Identifier("area".to_string())),
+                name: "area".to_string(),
+                parameters: vec![],
+                body: vec![ret(binary(member(this(), "radius"), TokenType::Star, member(this(), "radius")))],
+                return_type: Some(TypeAnnotation::Named("int".to_string())),
+                visibility: Visibility::Public,
+                is_static: false,
+                is_override: true,
+            },
+            ClassMember::AbstractMethod {
+                token: token(TokenType::Identifier("draw".to_string())),
+                name: "draw".to_string(),
+                parameters: vec![],
+                return_type: None,
+            },
+        ],
+    }];
+
+    let expected = "// implements Drawable
+class Circle extends Shape {
+  radius = 1;
+  static count;
+  static {
+    count = 0;
+  }
+  /**
+   * @returns {number}
+   */
+  area() {
+    return this.radius * this.radius;
+  }
+  draw() {
+    throw new Error(\"draw is abstract\");
+  }
+}
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_interface_becomes_comment() {
+    let program = vec![Statement::InterfaceDeclaration {
+        token: token(TokenType::InterfaceKeyword),
+        name: "Shape".to_string(),
+        members: vec![
+            InterfaceMember::Method {
+                token: token(TokenType::Identifier("scale".to_string())),
+                name: "scale".to_string(),
+                parameters: vec![("factor".to_string(), TypeAnnotation::Union(vec!["int".to_string(), "float".to_string()]))],
+                return_type: None,
+            },
+            InterfaceMember::Constant {
+                token: token(TokenType::ConstKeyword),
+                name: "SIDES".to_string(),
+                type_name: TypeAnnotation::Named("int".to_string()),
+                value: int(4),
+            },
+        ],
+    }];
+
+    let expected = "/*
+ * interface Shape {
+ *   scale(factor: number): void;
+ *   const SIDES: number = 4;
+ * }
+ */
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_reports_unsupported_code() {
+    let program = vec![Statement::Expression(Expression::Defer {
+        token: token(TokenType::DeferKeyword),
+        expression: Box::new(call("work", vec![])),
+    })];
+    let mut emitter = JsEmitter::new();
+    emitter.emit(&program);
+
+    assert_eq!(emitter.get_errors().len(), 1);
+    assert!(emitter.get_errors()[0].contains("Defer"));
+}
+
+#[test]
+fn test_js_delete() {
+    let program = vec![Statement::Expression(Expression::Delete {
+        token: token(TokenType::DeleteKeyword),
+        target: Box::new(member(ident("config"), "debug")),
+    })];
+    assert_eq!(emit(&program), "delete config.debug;\n");
+}
+
+#[test]
+fn test_js_freeze() {
+    let program = vec![var(
+        "config",
+        "Config",
+        member(
+            Expression::Freeze { token: token(TokenType::FreezeKeyword), value: Box::new(ident("defaults")) },
+            "debug",
+        ),
+    )];
+    assert_eq!(emit(&program), "let config = Object.freeze(defaults).debug;\n");
+}
+
+#[test]
+fn test_js_for_of_destructuring() {
+    let program = vec![Statement::ForEachStatement {
+        token: token(TokenType::ForKeyword),
+        index_variable: None,
+        element_variable: LoopVariable::Object(vec![
+            ("x".to_string(), "int".to_string()),
+            ("y".to_string(), "int".to_string()),
+        ]),
+        iterator: ident("points"),
+        body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call(
+            "print",
+            vec![binary(ident("x"), TokenType::Plus, ident("y"))],
+        ))])),
+    }];
+
+    let expected = "for (const { x, y } of points) {
+  print(x + y);
+}
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_default_exports() {
+    let export_default = |statement: Statement| Statement::ExportDeclaration {
+        token: token(TokenType::ExportKeyword),
+        specifiers: vec![ExportSpecifier::Default(Box::new(statement))],
+    };
+    let program = vec![
+        export_default(Statement::Expression(binary(int(1), TokenType::Plus, int(2)))),
+        export_default(function("main", &[], None, vec![])),
+    ];
+
+    let expected = "export default 1 + 2;
+function main() {
+}
+export default main;
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_js_super_call() {
+    let program = vec![
+        Statement::Expression(Expression::SuperCall { token: token(TokenType::SuperKeyword), arguments: vec![] }),
+        Statement::Expression(Expression::SuperCall {
+            token: token(TokenType::SuperKeyword),
+            arguments: vec![ident("name"), binary(ident("age"), TokenType::Plus, int(1))],
+        }),
+    ];
+    assert_eq!(emit(&program), "super();\nsuper(name, age + 1);\n");
+}
+
+#[test]
+fn test_js_for_in() {
+    let program = vec![Statement::ForInStatement {
+        token: token(TokenType::ForKeyword),
+        key_variable: "key".to_string(),
+        iterator: ident("scores"),
+        body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call("print", vec![ident("key")]))])),
+    }];
+
+    let expected = "for (const key in scores) {
+  print(key);
+}
+";
+    assert_eq!(emit(&program), expected);
+}
\ No newline at end of file
@@ -0,0 +1,499 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::ast::{Expression, LiteralValue, Statement, TypeAnnotation};
+use crate::codegen::compound_operator;
+use crate::token::TokenType;
+
+struct FunctionSignature {
+    parameter_count: usize,
+    returns_value: bool,
+}
+
+// Emits textual LLVM IR (.ll) from a parsed program. Only top-level function
+// declarations over int and bool values are supported; both are lowered to
+// i32, and every variable lives in an entry-block alloca. Addresses use the
+// opaque `ptr` type, so LLVM 14 needs `-opaque-pointers` to read the output.
+// Source names get a `%p.` (parameter) or `%v.` (variable address) prefix so
+// they can never clash with the emitter's own temporaries and block labels.
+pub struct LlvmIrEmitter {
+    functions: HashMap<String, FunctionSignature>,
+    locals: HashMap<String, String>,
+    declaration_counts: HashMap<String, usize>,
+    allocas: String,
+    body: String,
+    current_block: String,
+    terminated: bool,
+    temporary_count: usize,
+    label_count: usize,
+    errors: Vec<String>,
+}
+
+impl LlvmIrEmitter {
+    pub fn new() -> Self {
+        LlvmIrEmitter {
+            functions: HashMap::new(),
+            locals: HashMap::new(),
+            declaration_counts: HashMap::new(),
+            allocas: String::new(),
+            body: String::new(),
+            current_block: String::new(),
+            terminated: false,
+            temporary_count: 0,
+            label_count: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn get_errors(&self) -> &Vec<String> {
+        &self.errors
+    }
+
+    pub fn emit(&mut self, program: &[Statement]) -> String {
+        let mut declarations = Vec::new();
+
+        for statement in program {
+            match statement {
+                Statement::FunctionDeclaration { name, parameters, body, return_type, cleanup, .. } => {
+                    if cleanup.is_some() {
+                        self.errors.push(format!("Function '{}': finally blocks are not supported by the llvm-ir target", name));
+                    }
+
+                    let signature = FunctionSignature {
+                        parameter_count: parameters.len(),
+                        returns_value: return_type.is_some(),
+                    };
+                    self.functions.insert(name.clone(), signature);
+                    declarations.push((name, parameters, body, return_type));
+                }
+                _ => self.errors.push("Only function declarations are supported at the top level by the llvm-ir target".to_string()),
+            }
+        }
+
+        let mut module = String::new();
+        for (name, parameters, body, return_type) in declarations {
+            if !module.is_empty() {
+                module.push('\n');
+            }
+            module.push_str(&self.emit_function(name, parameters, body, return_type.as_ref()));
+        }
+        module
+    }
+
+    fn emit_function(
+        &mut self,
+        name: &str,
+        parameters: &[(String, TypeAnnotation)],
+        body: &[Statement],
+        return_type: Option<&TypeAnnotation>,
+    ) -> String {
+        self.locals.clear();
+        self.declaration_counts.clear();
+        self.allocas.clear();
+        self.body.clear();
+        self.current_block = "entry".to_string();
+        self.terminated = false;
+        self.temporary_count = 0;
+        self.label_count = 0;
+
+        let mut parameter_list = Vec::new();
+        for (parameter_name, type_annotation) in parameters {
+            self.check_type(parameter_name, type_annotation);
+            parameter_list.push(format!("i32 %p.{}", parameter_name));
+            let address = self.declare_local(parameter_name);
+            self.instruction(&format!("store i32 %p.{}, ptr {}", parameter_name, address));
+        }
+        if let Some(type_annotation) = return_type {
+            self.check_type(name, type_annotation);
+        }
+
+        for statement in body {
+            self.emit_statement(statement);
+        }
+        if !self.terminated {
+            // Falling off the end of a function that returns a value is undefined
+            let terminator = if return_type.is_some() { "unreachable" } else { "ret void" };
+            self.instruction(terminator);
+        }
+
+        let llvm_return_type = if return_type.is_some() { "i32" } else { "void" };
+        format!(
+            "define {} @{}({}) {{\nentry:\n{}{}}}\n",
+            llvm_return_type,
+            name,
+            parameter_list.join(", "),
+            self.allocas,
+            self.body
+        )
+    }
+
+    fn emit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::VariableDeclaration { name, type_name, value, .. } => {
+                if let Some(type_annotation) = type_name {
+                    self.check_type(name, type_annotation);
+                }
+                let value = value.as_ref().map(|value| self.emit_value(value));
+                let address = self.declare_local(name);
+                if let Some(value) = value {
+                    self.instruction(&format!("store i32 {}, ptr {}", value, address));
+                }
+            }
+            Statement::ReturnStatement { value, .. } => match value {
+                Some(value) => {
+                    let value = self.emit_value(value);
+                    self.instruction(&format!("ret i32 {}", value));
+                }
+                None => self.instruction("ret void"),
+            },
+            Statement::Expression(expression) => {
+                self.emit_expression(expression);
+            }
+            Statement::IfStatement { condition, then_branch, else_branch, .. } => {
+                let condition = self.emit_condition(condition);
+                let id = self.new_label_id();
+                let then_label = format!("if.then{}", id);
+                let else_label = format!("if.else{}", id);
+                let end_label = format!("if.end{}", id);
+                let false_label = if else_branch.is_some() { &else_label } else { &end_label };
+                self.instruction(&format!("br i1 {}, label %{}, label %{}", condition, then_label, false_label));
+
+                self.start_block(&then_label);
+                self.emit_statement(then_branch);
+                self.branch(&end_label);
+
+                if let Some(else_branch) = else_branch {
+                    self.start_block(&else_label);
+                    self.emit_statement(else_branch);
+                    self.branch(&end_label);
+                }
+
+                self.start_block(&end_label);
+            }
+            Statement::WhileStatement { condition, body, .. } => {
+                let id = self.new_label_id();
+                let condition_label = format!("while.cond{}", id);
+                let body_label = format!("while.body{}", id);
+                let end_label = format!("while.end{}", id);
+
+                self.branch(&condition_label);
+                self.start_block(&condition_label);
+                let condition = self.emit_condition(condition);
+                self.instruction(&format!("br i1 {}, label %{}, label %{}", condition, body_label, end_label));
+
+                self.start_block(&body_label);
+                self.emit_statement(body);
+                self.branch(&condition_label);
+
+                self.start_block(&end_label);
+            }
+            Statement::BlockStatement(statements) => {
+                for statement in statements {
+                    self.emit_statement(statement);
+                }
+            }
+            _ => self.errors.push("Unsupported statement in llvm-ir target".to_string()),
+        }
+    }
+
+    // Emits an expression that must produce a value and returns its operand
+    fn emit_value(&mut self, expression: &Expression) -> String {
+        match self.emit_expression(expression) {
+            Some(value) => value,
+            None => {
+                self.errors.push("Expression does not produce a value".to_string());
+                "undef".to_string()
+            }
+        }
+    }
+
+    // Emits an expression as an i1 suitable for a conditional branch
+    fn emit_condition(&mut self, expression: &Expression) -> String {
+        let value = self.emit_value(expression);
+        let condition = self.new_temporary();
+        self.instruction(&format!("{} = icmp ne i32 {}, 0", condition, value));
+        condition
+    }
+
+    // Returns the operand holding the expression's value, or None for calls
+    // to functions without a return type
+    fn emit_expression(&mut self, expression: &Expression) -> Option<String> {
+        match expression {
+            Expression::Literal { value, .. } => match value {
+                LiteralValue::Int(value) => Some(value.to_string()),
+                LiteralValue::Bool(value) => Some((*value as i32).to_string()),
+                _ => {
+                    self.errors.push("Only int and bool literals are supported by the llvm-ir target".to_string());
+                    None
+                }
+            },
+            Expression::Identifier { name, .. } => match self.locals.get(name).cloned() {
+                Some(address) => {
+                    let value = self.new_temporary();
+                    self.instruction(&format!("{} = load i32, ptr {}", value, address));
+                    Some(value)
+                }
+                None => {
+                    self.errors.push(format!("Unknown variable '{}'", name));
+                    None
+                }
+            },
+            Expression::BinaryOperation { left, operator, right, .. } => match operator {
+                TokenType::LogicalAnd => Some(self.emit_short_circuit(left, right, true)),
+                TokenType::LogicalOr => Some(self.emit_short_circuit(left, right, false)),
+                _ => {
+                    let left = self.emit_value(left);
+                    let right = self.emit_value(right);
+                    let value = self.new_temporary();
+                    match binary_instruction(operator) {
+                        Some((instruction, false)) => {
+                            self.instruction(&format!("{} = {} i32 {}, {}", value, instruction, left, right));
+                            Some(value)
+                        }
+                        Some((instruction, true)) => {
+                            self.instruction(&format!("{} = icmp {} i32 {}, {}", value, instruction, left, right));
+                            Some(self.extend_condition(&value))
+                        }
+                        None => {
+                            self.errors.push(format!("Unsupported binary operator {:?} in llvm-ir target", operator));
+                            None
+                        }
+                    }
+                }
+            },
+            Expression::UnaryOperation { operator, operand, .. } => {
+                let operand = self.emit_value(operand);
+                let value = self.new_temporary();
+                match operator {
+                    TokenType::Minus => {
+                        self.instruction(&format!("{} = sub i32 0, {}", value, operand));
+                        Some(value)
+                    }
+                    TokenType::LogicalNot => {
+                        self.instruction(&format!("{} = icmp eq i32 {}, 0", value, operand));
+                        Some(self.extend_condition(&value))
+                    }
+                    _ => {
+                        self.errors.push(format!("Unsupported unary operator {:?} in llvm-ir target", operator));
+                        None
+                    }
+                }
+            }
+            Expression::Assignment { token, left, right } => match &**left {
+                Expression::Identifier { name, .. } => {
+                    let address = match self.locals.get(name).cloned() {
+                        Some(address) => address,
+                        None => {
+                            self.errors.push(format!("Unknown variable '{}'", name));
+                            return None;
+                        }
+                    };
+
+                    let value = if token.token_type == TokenType::Equals {
+                        self.emit_value(right)
+                    } else {
+                        // `x += y` loads x, applies the operator, then stores the result
+                        match compound_operator(&token.token_type).as_ref().and_then(binary_instruction) {
+                            Some((instruction, false)) => {
+                                let current = self.new_temporary();
+                                self.instruction(&format!("{} = load i32, ptr {}", current, address));
+                                let right = self.emit_value(right);
+                                let value = self.new_temporary();
+                                self.instruction(&format!("{} = {} i32 {}, {}", value, instruction, current, right));
+                                value
+                            }
+                            _ => {
+                                self.errors.push(format!(
+                                    "Unsupported compound assignment {:?} in llvm-ir target",
+                                    token.token_type
+                                ));
+                                return None;
+                            }
+                        }
+                    };
+
+                    self.instruction(&format!("store i32 {}, ptr {}", value, address));
+                    Some(value)
+                }
+                _ => {
+                    self.errors.push("Only variables can be assigned to in the llvm-ir target".to_string());
+                    None
+                }
+            },
+            Expression::FunctionCall { callee, arguments, .. } => {
+                let name = match &**callee {
+                    Expression::Identifier { name, .. } => name,
+                    _ => {
+                        self.errors.push("Only direct function calls are supported by the llvm-ir target".to_string());
+                        return None;
+                    }
+                };
+
+                let arguments: Vec<String> = arguments.iter().map(|argument| format!("i32 {}", self.emit_value(argument))).collect();
+
+                let returns_value = match self.functions.get(name) {
+                    Some(signature) => {
+                        if signature.parameter_count != arguments.len() {
+                            self.errors.push(format!(
+                                "Function '{}' expects {} arguments but got {}",
+                                name,
+                                signature.parameter_count,
+                                arguments.len()
+                            ));
+                        }
+                        signature.returns_value
+                    }
+                    None => {
+                        self.errors.push(format!("Unknown function '{}'", name));
+                        return None;
+                    }
+                };
+
+                if returns_value {
+                    let value = self.new_temporary();
+                    self.instruction(&format!("{} = call i32 @{}({})", value, name, arguments.join(", ")));
+                    Some(value)
+                } else {
+                    self.instruction(&format!("call void @{}({})", name, arguments.join(", ")));
+                    None
+                }
+            }
+            Expression::Ternary { condition, then_expression, else_expression, .. } => {
+                let condition = self.emit_condition(condition);
+                let id = self.new_label_id();
+                let then_label = format!("cond.then{}", id);
+                let else_label = format!("cond.else{}", id);
+                let end_label = format!("cond.end{}", id);
+                self.instruction(&format!("br i1 {}, label %{}, label %{}", condition, then_label, else_label));
+
+                self.start_block(&then_label);
+                let then_value = self.emit_value(then_expression);
+                let then_block = self.current_block.clone();
+                self.branch(&end_label);
+
+                self.start_block(&else_label);
+                let else_value = self.emit_value(else_expression);
+                let else_block = self.current_block.clone();
+                self.branch(&end_label);
+
+                self.start_block(&end_label);
+                let value = self.new_temporary();
+                self.instruction(&format!(
+                    "{} = phi i32 [ {}, %{} ], [ {}, %{} ]",
+                    value, then_value, then_block, else_value, else_block
+                ));
+                Some(value)
+            }
+            _ => {
+                self.errors.push("Unsupported expression in llvm-ir target".to_string());
+                None
+            }
+        }
+    }
+
+    // && and || only evaluate the right operand when it decides the result
+    fn emit_short_circuit(&mut self, left: &Expression, right: &Expression, is_and: bool) -> String {
+        let prefix = if is_and { "and" } else { "or" };
+        let id = self.new_label_id();
+        let right_label = format!("{}.rhs{}", prefix, id);
+        let end_label = format!("{}.end{}", prefix, id);
+
+        let left = self.emit_condition(left);
+        let left_block = self.current_block.clone();
+        if is_and {
+            self.instruction(&format!("br i1 {}, label %{}, label %{}", left, right_label, end_label));
+        } else {
+            self.instruction(&format!("br i1 {}, label %{}, label %{}", left, end_label, right_label));
+        }
+
+        self.start_block(&right_label);
+        let right = self.emit_condition(right);
+        let right_block = self.current_block.clone();
+        self.branch(&end_label);
+
+        self.start_block(&end_label);
+        let result = self.new_temporary();
+        self.instruction(&format!(
+            "{} = phi i1 [ {}, %{} ], [ {}, %{} ]",
+            result, !is_and, left_block, right, right_block
+        ));
+        self.extend_condition(&result)
+    }
+
+    fn extend_condition(&mut self, condition: &str) -> String {
+        let value = self.new_temporary();
+        self.instruction(&format!("{} = zext i1 {} to i32", value, condition));
+        value
+    }
+
+    fn instruction(&mut self, text: &str) {
+        if self.terminated {
+            // Code after a return still needs a block to live in
+            let label = format!("dead{}", self.new_label_id());
+            self.start_block(&label);
+        }
+        writeln!(self.body, "  {}", text).unwrap();
+        self.terminated = text.starts_with("ret") || text.starts_with("br") || text == "unreachable";
+    }
+
+    fn branch(&mut self, label: &str) {
+        if !self.terminated {
+            self.instruction(&format!("br label %{}", label));
+        }
+    }
+
+    fn start_block(&mut self, label: &str) {
+        writeln!(self.body, "{}:", label).unwrap();
+        self.current_block = label.to_string();
+        self.terminated = false;
+    }
+
+    fn new_label_id(&mut self) -> usize {
+        self.label_count += 1;
+        self.label_count
+    }
+
+    fn new_temporary(&mut self) -> String {
+        self.temporary_count += 1;
+        format!("%t{}", self.temporary_count)
+    }
+
+    // Every declaration gets its own alloca, even when it shadows an earlier one
+    fn declare_local(&mut self, name: &str) -> String {
+        let count = self.declaration_counts.entry(name.to_string()).or_insert(0);
+        *count += 1;
+        let address = if *count == 1 {
+            format!("%v.{}", name)
+        } else {
+            format!("%v.{}.{}", name, count)
+        };
+        writeln!(self.allocas, "  {} = alloca i32", address).unwrap();
+        self.locals.insert(name.to_string(), address.clone());
+        address
+    }
+
+    fn check_type(&mut self, name: &str, type_annotation: &TypeAnnotation) {
+        match type_annotation {
+            TypeAnnotation::Named(type_name) if type_name == "int" || type_name == "bool" => {}
+            _ => self.errors.push(format!("'{}': only int and bool types are supported by the llvm-ir target", name)),
+        }
+    }
+}
+
+// Returns the instruction for an operator and whether it is an icmp predicate
+fn binary_instruction(operator: &TokenType) -> Option<(&'static str, bool)> {
+    match operator {
+        TokenType::Plus => Some(("add", false)),
+        TokenType::Minus => Some(("sub", false)),
+        TokenType::Star => Some(("mul", false)),
+        TokenType::Slash => Some(("sdiv", false)),
+        TokenType::Percent => Some(("srem", false)),
+        TokenType::EqualsEquals => Some(("eq", true)),
+        TokenType::NotEquals => Some(("ne", true)),
+        TokenType::LessThan => Some(("slt", true)),
+        TokenType::GreaterThan => Some(("sgt", true)),
+        TokenType::LessThanEquals => Some(("sle", true)),
+        TokenType::GreaterThanEquals => Some(("sge", true)),
+        _ => None,
+    }
+}
\ No newline at end of file
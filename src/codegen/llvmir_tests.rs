@@ -0,0 +1,236 @@
+use std::io::{self, Write};
+use std::process::{Command, Output, Stdio};
+
+use crate::ast::{Expression, Statement};
+use crate::codegen::llvmir::LlvmIrEmitter;
+use crate::test_ast::*;
+use crate::token::TokenType;
+
+fn emit(program: &[Statement]) -> String {
+    let mut emitter = LlvmIrEmitter::new();
+    let ir = emitter.emit(program);
+    assert!(emitter.get_errors().is_empty(), "unexpected errors: {:?}", emitter.get_errors());
+    ir
+}
+
+fn run_llvm_as(ir: &str, extra_args: &[&str]) -> io::Result<Output> {
+    let mut llvm_as = Command::new("llvm-as")
+        .args(extra_args)
+        .args(["-o", "/dev/null", "-"])
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    llvm_as.stdin.take().unwrap().write_all(ir.as_bytes())?;
+    llvm_as.wait_with_output()
+}
+
+// Skipped when llvm-as is not on PATH
+fn assert_assembles(ir: &str) {
+    let mut output = match run_llvm_as(ir, &[]) {
+        Ok(output) => output,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return,
+        Err(error) => panic!("failed to run llvm-as: {}", error),
+    };
+
+    // LLVM 14 only reads the opaque `ptr` type behind a flag; 15 and later read it by default
+    if String::from_utf8_lossy(&output.stderr).contains("-opaque-pointers") {
+        output = run_llvm_as(ir, &["-opaque-pointers"]).unwrap();
+    }
+    assert!(output.status.success(), "llvm-as rejected the IR:\n{}\n{}", String::from_utf8_lossy(&output.stderr), ir);
+}
+
+#[test]
+fn test_llvm_ir_add_function() {
+    let program = vec![function(
+        "add",
+        &[("a", "int"), ("b", "int")],
+        Some("int"),
+        vec![ret(binary(ident("a"), TokenType::Plus, ident("b")))],
+    )];
+    let ir = emit(&program);
+
+    let expected = "define i32 @add(i32 %p.a, i32 %p.b) {
+entry:
+  %v.a = alloca i32
+  %v.b = alloca i32
+  store i32 %p.a, ptr %v.a
+  store i32 %p.b, ptr %v.b
+  %t1 = load i32, ptr %v.a
+  %t2 = load i32, ptr %v.b
+  %t3 = add i32 %t1, %t2
+  ret i32 %t3
+}
+";
+    assert_eq!(ir, expected);
+    assert_assembles(&ir);
+}
+
+#[test]
+fn test_llvm_ir_if_else() {
+    // function sign(int n): int { if (n < 0) { return -1; } else { return 1; } }
+    let program = vec![function(
+        "sign",
+        &[("n", "int")],
+        Some("int"),
+        vec![if_else(
+            binary(ident("n"), TokenType::LessThan, int(0)),
+            vec![ret(int(-1))],
+            Some(vec![ret(int(1))]),
+        )],
+    )];
+    let ir = emit(&program);
+
+    let expected = "define i32 @sign(i32 %p.n) {
+entry:
+  %v.n = alloca i32
+  store i32 %p.n, ptr %v.n
+  %t1 = load i32, ptr %v.n
+  %t2 = icmp slt i32 %t1, 0
+  %t3 = zext i1 %t2 to i32
+  %t4 = icmp ne i32 %t3, 0
+  br i1 %t4, label %if.then1, label %if.else1
+if.then1:
+  ret i32 -1
+if.else1:
+  ret i32 1
+if.end1:
+  unreachable
+}
+";
+    assert_eq!(ir, expected);
+    assert_assembles(&ir);
+}
+
+#[test]
+fn test_llvm_ir_control_flow_assembles() {
+    let program = vec![
+        function("square", &[("n", "int")], Some("int"), vec![ret(binary(ident("n"), TokenType::Star, ident("n")))]),
+        function(
+            "sum_of_squares",
+            &[("n", "int")],
+            Some("int"),
+            vec![
+                var("total", "int", int(0)),
+                var("i", "int", int(1)),
+                while_loop(
+                    binary(ident("i"), TokenType::LessThanEquals, ident("n")),
+                    vec![
+                        Statement::Expression(assign(
+                            "total",
+                            binary(ident("total"), TokenType::Plus, call("square", vec![ident("i")])),
+                        )),
+                        Statement::Expression(assign("i", binary(ident("i"), TokenType::Plus, int(1)))),
+                    ],
+                ),
+                if_else(
+                    binary(
+                        binary(ident("total"), TokenType::GreaterThan, int(1000)),
+                        TokenType::LogicalOr,
+                        Expression::UnaryOperation {
+                            token: token(TokenType::LogicalNot),
+                            operator: TokenType::LogicalNot,
+                            operand: Box::new(boolean(true)),
+                        },
+                    ),
+                    vec![ret(int(1000))],
+                    None,
+                ),
+                ret(Expression::Ternary {
+                    token: token(TokenType::QuestionMark),
+                    condition: Box::new(binary(ident("n"), TokenType::GreaterThan, int(0))),
+                    then_expression: Box::new(ident("total")),
+                    else_expression: Box::new(int(0)),
+                }),
+                ret(int(0)),
+            ],
+        ),
+        function("noop", &[], None, vec![Statement::Expression(call("square", vec![int(2)]))]),
+        function("main", &[], Some("int"), vec![ret(call("sum_of_squares", vec![int(3)]))]),
+    ];
+    let ir = emit(&program);
+
+    assert!(ir.contains("while.cond1:"));
+    assert!(ir.contains("phi i1 [ true, %while.end1 ], [ %t18, %or.rhs2 ]"));
+    assert!(ir.contains("define void @noop() {"));
+    assert_assembles(&ir);
+}
+
+#[test]
+fn test_llvm_ir_compound_assignment() {
+    // function bump(int x): int { x += 1; return x; }
+    let program = vec![function(
+        "bump",
+        &[("x", "int")],
+        Some("int"),
+        vec![Statement::Expression(compound_assign("x", TokenType::PlusEquals, int(1))), ret(ident("x"))],
+    )];
+    let ir = emit(&program);
+
+    let expected = "define i32 @bump(i32 %p.x) {
+entry:
+  %v.x = alloca i32
+  store i32 %p.x, ptr %v.x
+  %t1 = load i32, ptr %v.x
+  %t2 = add i32 %t1, 1
+  store i32 %t2, ptr %v.x
+  %t3 = load i32, ptr %v.x
+  ret i32 %t3
+}
+";
+    assert_eq!(ir, expected);
+    assert_assembles(&ir);
+}
+
+#[test]
+fn test_llvm_ir_source_names_do_not_clash_with_generated_names() {
+    // function f(int t1, int entry): int { return t1 + entry; }
+    let program = vec![function(
+        "f",
+        &[("t1", "int"), ("entry", "int")],
+        Some("int"),
+        vec![ret(binary(ident("t1"), TokenType::Plus, ident("entry")))],
+    )];
+    let ir = emit(&program);
+
+    assert!(ir.contains("define i32 @f(i32 %p.t1, i32 %p.entry) {"));
+    assert!(ir.contains("%t1 = load i32, ptr %v.t1"));
+    assert_assembles(&ir);
+}
+
+#[test]
+fn test_llvm_ir_shadowed_declarations_get_their_own_allocas() {
+    // function f(): int { int x = 1; { int x = 2; { int x = 3; } } return 0; }
+    let program = vec![function(
+        "f",
+        &[],
+        Some("int"),
+        vec![
+            var("x", "int", int(1)),
+            Statement::BlockStatement(vec![
+                var("x", "int", int(2)),
+                Statement::BlockStatement(vec![var("x", "int", int(3))]),
+            ]),
+            ret(int(0)),
+        ],
+    )];
+    let ir = emit(&program);
+
+    assert!(ir.contains("  %v.x = alloca i32\n  %v.x.2 = alloca i32\n  %v.x.3 = alloca i32\n"));
+    assert!(ir.contains("store i32 3, ptr %v.x.3"));
+    assert_assembles(&ir);
+}
+
+#[test]
+fn test_llvm_ir_reports_unsupported_code() {
+    let program = vec![
+        var("x", "int", int(1)),
+        function("f", &[("s", "string")], Some("int"), vec![ret(call("missing", vec![]))]),
+    ];
+    let mut emitter = LlvmIrEmitter::new();
+    emitter.emit(&program);
+
+    let errors = emitter.get_errors();
+    assert!(errors.iter().any(|error| error.contains("top level")));
+    assert!(errors.iter().any(|error| error.contains("'s': only int and bool")));
+    assert!(errors.iter().any(|error| error.contains("Unknown function 'missing'")));
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use crate::token::TokenType;
+
+pub mod js;
+pub mod llvmir;
+pub mod python;
+pub mod wasm;
+
+#[cfg(test)]
+mod js_tests;
+#[cfg(test)]
+mod llvmir_tests;
+#[cfg(test)]
+mod python_tests;
+#[cfg(test)]
+mod wasm_tests;
+
+// Nesting depth of the source-emitting backends, each level `width` spaces wide
+pub struct IndentLevel {
+    depth: usize,
+    width: usize,
+}
+
+impl IndentLevel {
+    pub fn new(width: usize) -> Self {
+        IndentLevel { depth: 0, width }
+    }
+
+    pub fn increase(&mut self) {
+        self.depth += 1;
+    }
+
+    pub fn decrease(&mut self) {
+        self.depth -= 1;
+    }
+
+    pub fn prefix(&self) -> String {
+        " ".repeat(self.depth * self.width)
+    }
+}
+
+// The arithmetic operator a compound assignment applies, e.g. `Plus` for `+=`
+pub fn compound_operator(assignment: &TokenType) -> Option<TokenType> {
+    match assignment {
+        TokenType::PlusEquals => Some(TokenType::Plus),
+        TokenType::MinusEquals => Some(TokenType::Minus),
+        TokenType::StarEquals => Some(TokenType::Star),
+        TokenType::SlashEquals => Some(TokenType::Slash),
+        TokenType::PercentEquals => Some(TokenType::Percent),
+        _ => None,
+    }
+}
+
+// Quotes a string as a double-quoted literal that JavaScript and Python both accept
+pub fn quote_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
\ No newline at end of file
@@ -0,0 +1,796 @@
+use std::collections::{BTreeSet, HashSet};
+
+use crate::ast::{
+    ClassMember, ExportSpecifier, Expression, ImportSpecifier, InterfaceMember, LiteralValue, LoopVariable, ObjectProperty,
+    Statement, TypeAnnotation,
+};
+use crate::codegen::{quote_string, IndentLevel};
+use crate::token::TokenType;
+
+const PRECEDENCE_ASSIGNMENT: u8 = 1;
+const PRECEDENCE_CONDITIONAL: u8 = 2;
+const PRECEDENCE_OR: u8 = 3;
+const PRECEDENCE_AND: u8 = 4;
+const PRECEDENCE_NOT: u8 = 5;
+const PRECEDENCE_COMPARISON: u8 = 6;
+const PRECEDENCE_ADDITIVE: u8 = 11;
+const PRECEDENCE_MULTIPLICATIVE: u8 = 12;
+const PRECEDENCE_UNARY: u8 = 13;
+const PRECEDENCE_PRIMARY: u8 = 16;
+
+// What a `continue` has to run first in the innermost loop. C-style for loops
+// and do-while loops are lowered to `while`, which would otherwise skip the
+// increment or the condition check.
+enum LoopKind {
+    Plain,
+    For(Option<String>),
+    DoWhile(String),
+}
+
+// Transpiles a parsed program to Python 3 source
+pub struct PythonEmitter {
+    output: String,
+    indent: IndentLevel,
+    imports: BTreeSet<&'static str>,
+    loops: Vec<LoopKind>,
+    switch_count: usize,
+    int_variables: HashSet<String>,
+    errors: Vec<String>,
+}
+
+impl PythonEmitter {
+    pub fn new() -> Self {
+        Self::with_indent_width(4)
+    }
+
+    pub fn with_indent_width(width: usize) -> Self {
+        PythonEmitter {
+            output: String::new(),
+            indent: IndentLevel::new(width),
+            imports: BTreeSet::new(),
+            loops: Vec::new(),
+            switch_count: 0,
+            int_variables: HashSet::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn get_errors(&self) -> &Vec<String> {
+        &self.errors
+    }
+
+    pub fn emit(&mut self, program: &[Statement]) -> String {
+        self.emit_statements(program);
+
+        let body = std::mem::take(&mut self.output);
+        if self.imports.is_empty() {
+            return body;
+        }
+        let mut source = String::new();
+        for import in &self.imports {
+            source.push_str(import);
+            source.push('\n');
+        }
+        source.push('\n');
+        source.push_str(&body);
+        source
+    }
+
+    fn emit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::VariableDeclaration { name, type_name, value, .. } => {
+                self.declare(name, type_name.as_ref());
+                match value {
+                    Some(value) => {
+                        let value = self.expression(value);
+                        self.line(&format!("{} = {}", name, value));
+                    }
+                    None => self.line(&format!("{} = None", name)),
+                }
+            }
+            Statement::FunctionDeclaration { name, parameters, body, return_type, cleanup, .. } => {
+                self.declare_parameters(parameters);
+                let parameters = typed_parameters(parameters, false);
+                self.line(&format!("def {}({}){}:", name, parameters, return_annotation(return_type.as_ref())));
+                self.indented(|emitter| match cleanup {
+                    Some(cleanup) => {
+                        emitter.line("try:");
+                        emitter.indented(|emitter| emitter.emit_block(body));
+                        emitter.line("finally:");
+                        emitter.indented(|emitter| emitter.emit_block(cleanup));
+                    }
+                    None => emitter.emit_block(body),
+                });
+            }
+            Statement::ReturnStatement { value, .. } => match value {
+                Some(value) => {
+                    let value = self.expression(value);
+                    self.line(&format!("return {}", value));
+                }
+                None => self.line("return"),
+            },
+            Statement::Expression(expression) => {
+                let expression = self.statement_expression(expression);
+                self.line(&expression);
+            }
+            Statement::IfStatement { condition, then_branch, else_branch, .. } => {
+                self.emit_if("if", condition, then_branch, else_branch.as_deref());
+            }
+            Statement::DoWhileStatement { body, condition, .. } => {
+                let condition = self.operand(condition, PRECEDENCE_NOT + 1);
+                self.line("while True:");
+                self.emit_loop_body(body, LoopKind::DoWhile(condition.clone()));
+                self.indented(|emitter| {
+                    emitter.line(&format!("if not {}:", condition));
+                    emitter.indented(|emitter| emitter.line("break"));
+                });
+            }
+            Statement::WhileStatement { condition, body, .. } => {
+                let condition = self.expression(condition);
+                self.line(&format!("while {}:", condition));
+                self.emit_loop_body(body, LoopKind::Plain);
+            }
+            Statement::ForStatement { initializer, condition, increment, body, .. } => {
+                if let Some(initializer) = initializer {
+                    self.emit_statement(initializer);
+                }
+                let condition = match condition {
+                    Some(condition) => self.expression(condition),
+                    None => "True".to_string(),
+                };
+                let increment = increment.as_ref().map(|increment| self.statement_expression(increment));
+                self.line(&format!("while {}:", condition));
+                self.emit_loop_body(body, LoopKind::For(increment.clone()));
+                if let Some(increment) = increment {
+                    self.indented(|emitter| emitter.line(&increment));
+                }
+            }
+            Statement::ForEachStatement { index_variable, element_variable, iterator, body, .. } => {
+                let element_variable = match element_variable {
+                    LoopVariable::Simple(name, _) => name,
+                    LoopVariable::Object(_) => {
+                        self.errors.push("Destructured loop variables are not supported by the python target".to_string());
+                        return;
+                    }
+                };
+                let iterator = self.expression(iterator);
+                match index_variable {
+                    Some(index_variable) => {
+                        self.line(&format!("for {}, {} in enumerate({}):", index_variable, element_variable, iterator))
+                    }
+                    None => self.line(&format!("for {} in {}:", element_variable, iterator)),
+                }
+                self.emit_loop_body(body, LoopKind::Plain);
+            }
+            Statement::ForInStatement { key_variable, iterator, body, .. } => {
+                // Iterating a dict yields its keys
+                let iterator = self.expression(iterator);
+                self.line(&format!("for {} in {}:", key_variable, iterator));
+                self.emit_loop_body(body, LoopKind::Plain);
+            }
+            Statement::BreakStatement { .. } => self.line("break"),
+            Statement::ContinueStatement { .. } => {
+                match self.loops.last() {
+                    Some(LoopKind::For(Some(increment))) => {
+                        let increment = increment.clone();
+                        self.line(&increment);
+                    }
+                    Some(LoopKind::DoWhile(condition)) => {
+                        let condition = condition.clone();
+                        self.line(&format!("if not {}:", condition));
+                        self.indented(|emitter| emitter.line("break"));
+                    }
+                    _ => {}
+                }
+                self.line("continue");
+            }
+            Statement::EnumDeclaration { name, variants, .. } => {
+                self.imports.insert("from enum import Enum");
+                self.line(&format!("class {}(Enum):", name));
+                self.indented(|emitter| {
+                    for (index, variant) in variants.iter().enumerate() {
+                        emitter.line(&format!("{} = {}", variant, index));
+                    }
+                    if variants.is_empty() {
+                        emitter.line("pass");
+                    }
+                });
+            }
+            Statement::ObjectDeclaration { name, properties, .. } => {
+                let object = self.dict_literal(properties);
+                self.line(&format!("{} = {}", name, object));
+            }
+            Statement::UnionDeclaration { name, members, .. } => {
+                let members: Vec<&str> = members.iter().map(|member| python_type_name(member)).collect();
+                self.line(&format!("{} = {}", name, members.join(" | ")));
+            }
+            Statement::StructDeclaration { name, fields, .. } => {
+                self.imports.insert("from dataclasses import dataclass");
+                self.line("@dataclass");
+                self.line(&format!("class {}:", name));
+                self.indented(|emitter| {
+                    for (field, type_name) in fields {
+                        emitter.line(&format!("{}: {}", field, python_type_name(type_name)));
+                    }
+                    if fields.is_empty() {
+                        emitter.line("pass");
+                    }
+                });
+            }
+            Statement::ClassDeclaration { name, superclass, mixins, interfaces, members, .. } => {
+                // Methods may name their own class in annotations
+                self.imports.insert("from __future__ import annotations");
+                // Later mixins override earlier ones, which override the superclass
+                let bases: Vec<&str> =
+                    mixins.iter().rev().chain(superclass.iter()).chain(interfaces.iter()).map(String::as_str).collect();
+                if bases.is_empty() {
+                    self.line(&format!("class {}:", name));
+                } else {
+                    self.line(&format!("class {}({}):", name, bases.join(", ")));
+                }
+                self.indented(|emitter| {
+                    for member in members {
+                        emitter.emit_class_member(member);
+                    }
+                    if members.is_empty() {
+                        emitter.line("pass");
+                    }
+                });
+            }
+            Statement::InterfaceDeclaration { name, members, .. } => {
+                self.imports.insert("from typing import Protocol");
+                self.line(&format!("class {}(Protocol):", name));
+                self.indented(|emitter| {
+                    for member in members {
+                        match member {
+                            InterfaceMember::Method { name, parameters, return_type, .. } => {
+                                let parameters = typed_parameters(parameters, true);
+                                let return_type = return_annotation(return_type.as_ref());
+                                emitter.line(&format!("def {}({}){}: ...", name, parameters, return_type));
+                            }
+                            InterfaceMember::Constant { name, type_name, value, .. } => {
+                                let value = emitter.expression(value);
+                                emitter.line(&format!("{}: {} = {}", name, python_type(type_name), value));
+                            }
+                        }
+                    }
+                    if members.is_empty() {
+                        emitter.line("pass");
+                    }
+                });
+            }
+            Statement::ImportDeclaration { path, imports, .. } => {
+                let module = module_name(path);
+                let mut named = Vec::new();
+                for import in imports {
+                    match import {
+                        ImportSpecifier::Named(name) => named.push(name.as_str()),
+                        ImportSpecifier::Default { name, alias } => {
+                            let binding = alias.as_ref().unwrap_or(name);
+                            self.line(&format!("import {} as {}", module, binding));
+                        }
+                    }
+                }
+                if !named.is_empty() {
+                    self.line(&format!("from {} import {}", module, named.join(", ")));
+                }
+            }
+            Statement::ExportDeclaration { specifiers, .. } => {
+                let mut named = Vec::new();
+                for specifier in specifiers {
+                    match specifier {
+                        ExportSpecifier::Named(name) => named.push(quote_string(name)),
+                        ExportSpecifier::Default(_) => {
+                            self.errors.push("Default exports are not supported by the python target".to_string())
+                        }
+                    }
+                }
+                if !named.is_empty() {
+                    self.line(&format!("__all__ = [{}]", named.join(", ")));
+                }
+            }
+            Statement::SwitchStatement { expression, cases, default, .. } => {
+                self.emit_switch(expression, cases, default.as_deref());
+            }
+            Statement::CancelStatement { .. } => {
+                self.errors.push("Cancel statements are not supported by the python target".to_string());
+            }
+            Statement::BlockStatement(statements) => {
+                // Python has no block scope, so the statements are inlined
+                self.emit_statements(statements);
+            }
+        }
+    }
+
+    fn emit_class_member(&mut self, member: &ClassMember) {
+        match member {
+            ClassMember::Field { name, type_name, value, .. } => {
+                let annotation = type_name.as_ref().map(|type_name| format!(": {}", python_type_name(type_name)));
+                match (annotation, value) {
+                    (annotation, Some(value)) => {
+                        let value = self.expression(value);
+                        self.line(&format!("{}{} = {}", name, annotation.unwrap_or_default(), value));
+                    }
+                    (Some(annotation), None) => self.line(&format!("{}{}", name, annotation)),
+                    (None, None) => self.line(&format!("{} = None", name)),
+                }
+            }
+            ClassMember::Method { name, parameters, body, return_type, is_static, .. } => {
+                if *is_static {
+                    self.line("@staticmethod");
+                }
+                self.declare_parameters(parameters);
+                let parameters = typed_parameters(parameters, !is_static);
+                let name = if name == "constructor" && !is_static { "__init__" } else { name };
+                self.line(&format!("def {}({}){}:", name, parameters, return_annotation(return_type.as_ref())));
+                self.indented(|emitter| emitter.emit_block(body));
+            }
+            ClassMember::AbstractMethod { name, parameters, return_type, .. } => {
+                let parameters = typed_parameters(parameters, true);
+                self.line(&format!("def {}({}){}:", name, parameters, return_annotation(return_type.as_ref())));
+                self.indented(|emitter| emitter.line("raise NotImplementedError"));
+            }
+            ClassMember::StaticInitializer { body, .. } => {
+                // A class body already runs once, when the class is defined
+                self.emit_statements(body);
+            }
+        }
+    }
+
+    fn emit_if(&mut self, keyword: &str, condition: &Expression, then_branch: &Statement, else_branch: Option<&Statement>) {
+        let condition = self.expression(condition);
+        self.line(&format!("{} {}:", keyword, condition));
+        self.emit_body(then_branch);
+
+        match else_branch {
+            Some(Statement::IfStatement { condition, then_branch, else_branch, .. }) => {
+                self.emit_if("elif", condition, then_branch, else_branch.as_deref());
+            }
+            Some(Statement::BlockStatement(statements))
+                if statements.len() == 1 && matches!(statements[0], Statement::IfStatement { .. }) =>
+            {
+                if let Statement::IfStatement { condition, then_branch, else_branch, .. } = &statements[0] {
+                    self.emit_if("elif", condition, then_branch, else_branch.as_deref());
+                }
+            }
+            Some(else_branch) => {
+                self.line("else:");
+                self.emit_body(else_branch);
+            }
+            None => {}
+        }
+    }
+
+    // Cases become an if/elif chain. The break that ends a case is dropped,
+    // since in Python it would leave the enclosing loop instead. An if/elif
+    // chain cannot fall through or break out early, so cases that do either
+    // are reported.
+    fn emit_switch(&mut self, expression: &Expression, cases: &[(Vec<Expression>, Vec<Statement>)], default: Option<&[Statement]>) {
+        let subject = match expression {
+            Expression::Identifier { name, .. } => name.clone(),
+            _ => {
+                self.switch_count += 1;
+                let subject = format!("_switch_{}", self.switch_count);
+                let value = self.expression(expression);
+                self.line(&format!("{} = {}", subject, value));
+                subject
+            }
+        };
+
+        for (index, (values, body)) in cases.iter().enumerate() {
+            let body = unbraced(body);
+            let is_last_clause = default.is_none() && index == cases.len() - 1;
+            if !is_last_clause && falls_through(body) {
+                self.errors.push("Switch cases that fall through are not supported by the python target".to_string());
+            }
+            if contains_switch_break(without_trailing_break(body)) {
+                self.errors.push("Breaking out of a switch case early is not supported by the python target".to_string());
+            }
+
+            let conditions: Vec<String> = values
+                .iter()
+                .map(|value| format!("{} == {}", subject, self.operand(value, PRECEDENCE_COMPARISON + 1)))
+                .collect();
+            let keyword = if index == 0 { "if" } else { "elif" };
+            self.line(&format!("{} {}:", keyword, conditions.join(" or ")));
+            self.indented(|emitter| emitter.emit_block(without_trailing_break(body)));
+        }
+
+        if let Some(body) = default {
+            let body = unbraced(body);
+            if contains_switch_break(without_trailing_break(body)) {
+                self.errors.push("Breaking out of a switch case early is not supported by the python target".to_string());
+            }
+
+            if cases.is_empty() {
+                self.emit_statements(without_trailing_break(body));
+            } else {
+                self.line("else:");
+                self.indented(|emitter| emitter.emit_block(without_trailing_break(body)));
+            }
+        }
+    }
+
+    fn emit_loop_body(&mut self, body: &Statement, kind: LoopKind) {
+        self.loops.push(kind);
+        self.emit_body(body);
+        self.loops.pop();
+    }
+
+    // Emits an indented branch or loop body, unwrapping blocks
+    fn emit_body(&mut self, body: &Statement) {
+        self.indented(|emitter| match body {
+            Statement::BlockStatement(statements) => emitter.emit_block(statements),
+            statement => emitter.emit_statement(statement),
+        });
+    }
+
+    // Emits statements that form a suite, which must not be empty
+    fn emit_block(&mut self, statements: &[Statement]) {
+        if statements.is_empty() {
+            self.line("pass");
+        }
+        self.emit_statements(statements);
+    }
+
+    fn emit_statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.emit_statement(statement);
+        }
+    }
+
+    // Remembers which names are declared `int`, so that dividing them can use
+    // `//`. Names are tracked by spelling only, without scopes.
+    fn declare(&mut self, name: &str, type_annotation: Option<&TypeAnnotation>) {
+        if matches!(type_annotation, Some(TypeAnnotation::Named(type_name)) if type_name == "int") {
+            self.int_variables.insert(name.to_string());
+        } else {
+            self.int_variables.remove(name);
+        }
+    }
+
+    fn declare_parameters(&mut self, parameters: &[(String, TypeAnnotation)]) {
+        for (name, type_annotation) in parameters {
+            self.declare(name, Some(type_annotation));
+        }
+    }
+
+    // Whether an expression is known to be an int. Python's `/` always yields
+    // a float, so int division is written `//`. That floors rather than
+    // truncating, which only differs for negative operands.
+    fn is_int(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Literal { value: LiteralValue::Int(_), .. } => true,
+            Expression::Identifier { name, .. } => self.int_variables.contains(name),
+            Expression::BinaryOperation { left, operator, right, .. } => {
+                matches!(
+                    operator,
+                    TokenType::Plus | TokenType::Minus | TokenType::Star | TokenType::Slash | TokenType::Percent
+                ) && self.is_int(left)
+                    && self.is_int(right)
+            }
+            Expression::UnaryOperation { operator: TokenType::Minus, operand, .. } => self.is_int(operand),
+            _ => false,
+        }
+    }
+
+    // Assignments are statements in Python, so at the top of an expression
+    // statement they are written without the walrus operator
+    fn statement_expression(&mut self, expression: &Expression) -> String {
+        match expression {
+            Expression::Assignment { token, left, right } => {
+                let operator_text = match assignment_operator(&token.token_type) {
+                    Some(operator_text) => operator_text,
+                    None => {
+                        self.errors.push(format!("Unsupported assignment operator {:?} in python target", token.token_type));
+                        return String::new();
+                    }
+                };
+                let operator_text = if token.token_type == TokenType::SlashEquals && self.is_int(left) && self.is_int(right) {
+                    "//="
+                } else {
+                    operator_text
+                };
+                let left = self.operand(left, PRECEDENCE_PRIMARY);
+                let right = self.expression(right);
+                format!("{} {} {}", left, operator_text, right)
+            }
+            Expression::Delete { target, .. } => {
+                let target = self.operand(target, PRECEDENCE_PRIMARY);
+                format!("del {}", target)
+            }
+            _ => self.expression(expression),
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression) -> String {
+        self.emit_expression(expression).0
+    }
+
+    // Emits an expression, parenthesised if it binds looser than `precedence`
+    fn operand(&mut self, expression: &Expression, precedence: u8) -> String {
+        let (text, expression_precedence) = self.emit_expression(expression);
+        if expression_precedence < precedence {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[Expression]) -> String {
+        let arguments: Vec<String> =
+            arguments.iter().map(|argument| self.operand(argument, PRECEDENCE_CONDITIONAL)).collect();
+        arguments.join(", ")
+    }
+
+    // Returns the Python text of an expression and its precedence
+    fn emit_expression(&mut self, expression: &Expression) -> (String, u8) {
+        match expression {
+            Expression::Literal { value, .. } => {
+                let text = match value {
+                    LiteralValue::Int(value) => value.to_string(),
+                    LiteralValue::Float(value) => format!("{:?}", value),
+                    LiteralValue::String(value) => quote_string(value),
+                    LiteralValue::Bool(true) => "True".to_string(),
+                    LiteralValue::Bool(false) => "False".to_string(),
+                };
+                (text, PRECEDENCE_PRIMARY)
+            }
+            Expression::Identifier { name, .. } => (name.clone(), PRECEDENCE_PRIMARY),
+            Expression::BinaryOperation { left, operator, right, .. } => {
+                let (operator_text, precedence) = match binary_operator(operator) {
+                    Some(operator) => operator,
+                    None => {
+                        self.errors.push(format!("Unsupported binary operator {:?} in python target", operator));
+                        return (String::new(), PRECEDENCE_PRIMARY);
+                    }
+                };
+                let operator_text = if *operator == TokenType::Slash && self.is_int(left) && self.is_int(right) {
+                    "//"
+                } else {
+                    operator_text
+                };
+                // Python chains comparisons, so `(a < b) < c` keeps its parentheses
+                let left_precedence = if precedence == PRECEDENCE_COMPARISON { precedence + 1 } else { precedence };
+                let left = self.operand(left, left_precedence);
+                let right = self.operand(right, precedence + 1);
+                (format!("{} {} {}", left, operator_text, right), precedence)
+            }
+            Expression::UnaryOperation { operator, operand, .. } => match operator {
+                TokenType::Minus => {
+                    let operand = self.operand(operand, PRECEDENCE_UNARY);
+                    let separator = if operand.starts_with('-') { " " } else { "" };
+                    (format!("-{}{}", separator, operand), PRECEDENCE_UNARY)
+                }
+                TokenType::LogicalNot => {
+                    let operand = self.operand(operand, PRECEDENCE_NOT);
+                    (format!("not {}", operand), PRECEDENCE_NOT)
+                }
+                _ => {
+                    self.errors.push(format!("Unsupported unary operator {:?} in python target", operator));
+                    (String::new(), PRECEDENCE_PRIMARY)
+                }
+            },
+            // `:=` has no compound form, so `x += 1` only works as a statement
+            Expression::Assignment { token, .. } if token.token_type != TokenType::Equals => {
+                self.errors.push(format!(
+                    "Compound assignment {:?} inside an expression is not supported by the python target",
+                    token.token_type
+                ));
+                (String::new(), PRECEDENCE_PRIMARY)
+            }
+            Expression::Assignment { left, right, .. } => match &**left {
+                Expression::Identifier { name, .. } => {
+                    let right = self.operand(right, PRECEDENCE_CONDITIONAL);
+                    (format!("{} := {}", name, right), PRECEDENCE_ASSIGNMENT)
+                }
+                _ => {
+                    self.errors.push("Only variables can be assigned inside expressions in the python target".to_string());
+                    (String::new(), PRECEDENCE_PRIMARY)
+                }
+            },
+            Expression::FunctionCall { callee, arguments, .. } => {
+                let callee = self.operand(callee, PRECEDENCE_PRIMARY);
+                let arguments = self.arguments(arguments);
+                (format!("{}({})", callee, arguments), PRECEDENCE_PRIMARY)
+            }
+            Expression::ArrayLiteral { elements, .. } => {
+                let elements = self.arguments(elements);
+                (format!("[{}]", elements), PRECEDENCE_PRIMARY)
+            }
+            Expression::IndexAccess { array, index, .. } => {
+                let array = self.operand(array, PRECEDENCE_PRIMARY);
+                let index = self.expression(index);
+                (format!("{}[{}]", array, index), PRECEDENCE_PRIMARY)
+            }
+            Expression::SliceAccess { array, start, end, step, .. } => {
+                let array = self.operand(array, PRECEDENCE_PRIMARY);
+                let start = start.as_ref().map(|start| self.expression(start)).unwrap_or_default();
+                let end = end.as_ref().map(|end| self.expression(end)).unwrap_or_default();
+                let slice = match step {
+                    Some(step) => format!("{}:{}:{}", start, end, self.expression(step)),
+                    None => format!("{}:{}", start, end),
+                };
+                (format!("{}[{}]", array, slice), PRECEDENCE_PRIMARY)
+            }
+            Expression::MemberAccess { object, member, .. } => {
+                let object = self.operand(object, PRECEDENCE_PRIMARY);
+                (format!("{}.{}", object, member), PRECEDENCE_PRIMARY)
+            }
+            Expression::Ternary { condition, then_expression, else_expression, .. } => {
+                let then_expression = self.operand(then_expression, PRECEDENCE_OR);
+                let condition = self.operand(condition, PRECEDENCE_OR);
+                let else_expression = self.operand(else_expression, PRECEDENCE_CONDITIONAL);
+                (format!("{} if {} else {}", then_expression, condition, else_expression), PRECEDENCE_CONDITIONAL)
+            }
+            Expression::DictLiteral { pairs, .. } => (self.dict_literal(pairs), PRECEDENCE_PRIMARY),
+            Expression::NewExpression { class_name, arguments, .. } => {
+                let arguments = self.arguments(arguments);
+                (format!("{}({})", class_name, arguments), PRECEDENCE_PRIMARY)
+            }
+            Expression::This { .. } => ("self".to_string(), PRECEDENCE_PRIMARY),
+            Expression::SuperCall { arguments, .. } => {
+                let arguments = self.arguments(arguments);
+                (format!("super().__init__({})", arguments), PRECEDENCE_PRIMARY)
+            }
+            Expression::Clone { value, .. } => {
+                self.imports.insert("import copy");
+                let value = self.expression(value);
+                (format!("copy.deepcopy({})", value), PRECEDENCE_PRIMARY)
+            }
+            Expression::Defer { .. } => {
+                self.errors.push("Defer expressions are not supported by the python target".to_string());
+                (String::new(), PRECEDENCE_PRIMARY)
+            }
+            Expression::Freeze { .. } => {
+                self.errors.push("Freeze expressions are not supported by the python target".to_string());
+                (String::new(), PRECEDENCE_PRIMARY)
+            }
+            // `del` is a statement in Python, so it has no value to produce here
+            Expression::Delete { .. } => {
+                self.errors.push("Delete is only supported as a statement by the python target".to_string());
+                (String::new(), PRECEDENCE_PRIMARY)
+            }
+            Expression::NamedArgument { name, value, .. } => {
+                let value = self.operand(value, PRECEDENCE_CONDITIONAL);
+                (format!("{}={}", name, value), PRECEDENCE_PRIMARY)
+            }
+        }
+    }
+
+    fn dict_literal(&mut self, properties: &[ObjectProperty]) -> String {
+        let properties: Vec<String> = properties
+            .iter()
+            .map(|property| match property {
+                ObjectProperty::Named(key, value) => {
+                    format!("{}: {}", quote_string(key), self.operand(value, PRECEDENCE_CONDITIONAL))
+                }
+                ObjectProperty::Computed(key, value) => {
+                    format!("{}: {}", self.operand(key, PRECEDENCE_CONDITIONAL), self.operand(value, PRECEDENCE_CONDITIONAL))
+                }
+            })
+            .collect();
+        format!("{{{}}}", properties.join(", "))
+    }
+
+    fn indented(&mut self, emit: impl FnOnce(&mut Self)) {
+        self.indent.increase();
+        emit(self);
+        self.indent.decrease();
+    }
+
+    fn line(&mut self, text: &str) {
+        self.output.push_str(&self.indent.prefix());
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+}
+
+// A braced case body only adds a scope, which Python does not have
+fn unbraced(body: &[Statement]) -> &[Statement] {
+    match body {
+        [Statement::BlockStatement(statements)] => statements,
+        _ => body,
+    }
+}
+
+fn without_trailing_break(body: &[Statement]) -> &[Statement] {
+    match body.last() {
+        Some(Statement::BreakStatement { .. }) => &body[..body.len() - 1],
+        _ => body,
+    }
+}
+
+// Whether a case body can run off its end into the next case
+fn falls_through(body: &[Statement]) -> bool {
+    !matches!(
+        body.last(),
+        Some(Statement::BreakStatement { .. } | Statement::ReturnStatement { .. } | Statement::ContinueStatement { .. })
+    )
+}
+
+// Whether any `break` here targets the switch; loops and nested switches bind their own
+fn contains_switch_break(statements: &[Statement]) -> bool {
+    statements.iter().any(|statement| match statement {
+        Statement::BreakStatement { .. } => true,
+        Statement::BlockStatement(statements) => contains_switch_break(statements),
+        Statement::IfStatement { then_branch, else_branch, .. } => {
+            contains_switch_break(std::slice::from_ref(&**then_branch))
+                || else_branch.as_deref().is_some_and(|else_branch| contains_switch_break(std::slice::from_ref(else_branch)))
+        }
+        _ => false,
+    })
+}
+
+fn typed_parameters(parameters: &[(String, TypeAnnotation)], with_self: bool) -> String {
+    let mut list: Vec<String> = Vec::new();
+    if with_self {
+        list.push("self".to_string());
+    }
+    for (name, type_annotation) in parameters {
+        list.push(format!("{}: {}", name, python_type(type_annotation)));
+    }
+    list.join(", ")
+}
+
+fn return_annotation(return_type: Option<&TypeAnnotation>) -> String {
+    match return_type {
+        Some(return_type) => format!(" -> {}", python_type(return_type)),
+        None => " -> None".to_string(),
+    }
+}
+
+fn python_type(type_annotation: &TypeAnnotation) -> String {
+    match type_annotation {
+        TypeAnnotation::Named(name) => python_type_name(name).to_string(),
+        TypeAnnotation::Union(names) => {
+            let names: Vec<&str> = names.iter().map(|name| python_type_name(name)).collect();
+            names.join(" | ")
+        }
+    }
+}
+
+fn python_type_name(name: &str) -> &str {
+    match name {
+        "string" => "str",
+        other => other,
+    }
+}
+
+// "./utils/math" becomes "utils.math"
+fn module_name(path: &str) -> String {
+    let path = path.trim_start_matches("./");
+    let path = path.strip_suffix(".jal").unwrap_or(path);
+    path.replace('/', ".")
+}
+
+fn assignment_operator(operator: &TokenType) -> Option<&'static str> {
+    match operator {
+        TokenType::Equals => Some("="),
+        TokenType::PlusEquals => Some("+="),
+        TokenType::MinusEquals => Some("-="),
+        TokenType::StarEquals => Some("*="),
+        TokenType::SlashEquals => Some("/="),
+        TokenType::PercentEquals => Some("%="),
+        _ => None,
+    }
+}
+
+fn binary_operator(operator: &TokenType) -> Option<(&'static str, u8)> {
+    match operator {
+        TokenType::LogicalOr => Some(("or", PRECEDENCE_OR)),
+        TokenType::LogicalAnd => Some(("and", PRECEDENCE_AND)),
+        TokenType::EqualsEquals => Some(("==", PRECEDENCE_COMPARISON)),
+        TokenType::NotEquals => Some(("!=", PRECEDENCE_COMPARISON)),
+        TokenType::LessThan => Some(("<", PRECEDENCE_COMPARISON)),
+        TokenType::GreaterThan => Some((">", PRECEDENCE_COMPARISON)),
+        TokenType::LessThanEquals => Some(("<=", PRECEDENCE_COMPARISON)),
+        TokenType::GreaterThanEquals => Some((">=", PRECEDENCE_COMPARISON)),
+        TokenType::Plus => Some(("+", PRECEDENCE_ADDITIVE)),
+        TokenType::Minus => Some(("-", PRECEDENCE_ADDITIVE)),
+        TokenType::Star => Some(("*", PRECEDENCE_MULTIPLICATIVE)),
+        TokenType::Slash => Some(("/", PRECEDENCE_MULTIPLICATIVE)),
+        TokenType::Percent => Some(("%", PRECEDENCE_MULTIPLICATIVE)),
+        _ => None,
+    }
+}
\ No newline at end of file
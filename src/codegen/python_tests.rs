@@ -0,0 +1,448 @@
+use std::io;
+use std::process::Command;
+
+use crate::ast::{
+    ClassMember, Expression, InterfaceMember, LiteralValue, LoopVariable, Statement, TypeAnnotation, Visibility,
+};
+use crate::codegen::python::PythonEmitter;
+use crate::test_ast::*;
+use crate::token::TokenType;
+
+fn emit(program: &[Statement]) -> String {
+    let mut emitter = PythonEmitter::new();
+    let source = emitter.emit(program);
+    assert!(emitter.get_errors().is_empty(), "unexpected errors: {:?}", emitter.get_errors());
+    source
+}
+
+fn string(value: &str) -> Expression {
+    Expression::Literal { token: token(TokenType::String(value.to_string())), value: LiteralValue::String(value.to_string()) }
+}
+
+#[test]
+fn test_python_variable_declarations() {
+    let program = vec![
+        var("x", "int", int(5)),
+        var("ok", "bool", binary(boolean(true), TokenType::LogicalAnd, binary(ident("x"), TokenType::GreaterThan, int(1)))),
+        var("label", "string", string("it's \"x\"")),
+        var("flag", "bool", binary(binary(ident("a"), TokenType::LessThan, ident("b")), TokenType::EqualsEquals, ident("c"))),
+        Statement::Expression(assign("x", binary(ident("x"), TokenType::Star, binary(ident("x"), TokenType::Plus, int(1))))),
+    ];
+
+    let expected = "x = 5
+ok = True and x > 1
+label = \"it's \\\"x\\\"\"
+flag = (a < b) == c
+x = x * (x + 1)
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_python_compound_assignment() {
+    let program = vec![
+        Statement::Expression(compound_assign("x", TokenType::PlusEquals, int(1))),
+        Statement::Expression(compound_assign("x", TokenType::StarEquals, binary(ident("y"), TokenType::Plus, int(2)))),
+    ];
+    assert_eq!(emit(&program), "x += 1\nx *= y + 2\n");
+
+    // Inside an expression it would need `:=`, which has no compound form
+    let program = vec![Statement::Expression(call("f", vec![compound_assign("x", TokenType::MinusEquals, int(1))]))];
+    let mut emitter = PythonEmitter::new();
+    emitter.emit(&program);
+    assert!(emitter.get_errors().iter().any(|error| error.starts_with("Compound assignment MinusEquals")));
+}
+
+#[test]
+fn test_python_function_declaration() {
+    let program = vec![
+        function("foo", &[("x", "int")], Some("int"), vec![]),
+        function(
+            "describe",
+            &[("name", "string"), ("score", "float")],
+            None,
+            vec![if_else(
+                binary(ident("score"), TokenType::GreaterThanEquals, int(50)),
+                vec![Statement::Expression(call("print", vec![ident("name")]))],
+                Some(vec![if_else(
+                    binary(ident("score"), TokenType::GreaterThan, int(0)),
+                    vec![ret(call("retry", vec![]))],
+                    Some(vec![Statement::ReturnStatement { token: token(TokenType::ReturnKeyword), value: None }]),
+                )]),
+            )],
+        ),
+    ];
+
+    let expected = "def foo(x: int) -> int:
+    pass
+def describe(name: str, score: float) -> None:
+    if score >= 50:
+        print(name)
+    elif score > 0:
+        return retry()
+    else:
+        return
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_python_class_declaration() {
+    let this = || Expression::This { token: token(TokenType::ThisKeyword) };
+    let program = vec![
+        Statement::InterfaceDeclaration {
+            token: token(TokenType::InterfaceKeyword),
+            name: "Shape".to_string(),
+            members: vec![
+                InterfaceMember::Method {
+                    token: token(TokenType::Identifier("area".to_string())),
+                    name: "area".to_string(),
+                    parameters: vec![],
+                    return_type: Some(TypeAnnotation::Union(vec!["int".to_string(), "float".to_string()])),
+                },
+                InterfaceMember::Constant {
+                    token: token(TokenType::ConstKeyword),
+                    name: "SIDES".to_string(),
+                    type_name: TypeAnnotation::Named("int".to_string()),
+                    value: int(0),
+                },
+            ],
+        },
+        Statement::ClassDeclaration {
+            token: token(TokenType::ClassKeyword),
+            name: "Circle".to_string(),
+            superclass: Some("Base".to_string()),
+            mixins: vec!["Serializable".to_string(), "Printable".to_string()],
+            interfaces: vec!["Shape".to_string()],
+            members: vec![
+                ClassMember::Field {
+                    token: token(TokenType::Identifier("radius".to_string())),
+                    name: "radius".to_string(),
+                    type_name: Some("float".to_string()),
+                    value: Some(int(1)),
+                    visibility: Visibility::Public,
+                    is_static: false,
+                },
+                ClassMember::Method {
+                    token: token(TokenType::Identifier("area".to_string())),
+                    name: "area".to_string(),
+                    parameters: vec![],
+                    body: vec![ret(Expression::MemberAccess {
+                        token: token(TokenType::Dot),
+                        object: Box::new(this()),
+                        member: "radius".to_string(),
+                    })],
+                    return_type: Some(TypeAnnotation::Named("float".to_string())),
+                    visibility: Visibility::Public,
+                    is_static: false,
+                    is_override: false,
+                },
+                ClassMember::Method {
+                    token: token(TokenType::Identifier("unit".to_string())),
+                    name: "unit".to_string(),
+                    parameters: vec![],
+                    body: vec![ret(Expression::NewExpression {
+                        token: token(TokenType::NewKeyword),
+                        class_name: "Circle".to_string(),
+                        arguments: vec![],
+                    })],
+                    return_type: Some(TypeAnnotation::Named("Circle".to_string())),
+                    visibility: Visibility::Public,
+                    is_static: true,
+                    is_override: false,
+                },
+            ],
+        },
+    ];
+
+    let expected = "from __future__ import annotations
+from typing import Protocol
+
+class Shape(Protocol):
+    def area(self) -> int | float: ...
+    SIDES: int = 0
+class Circle(Printable, Serializable, Base, Shape):
+    radius: float = 1
+    def area(self) -> float:
+        return self.radius
+    @staticmethod
+    def unit() -> Circle:
+        return Circle()
+";
+    assert_eq!(emit(&program), expected);
+}
+
+// Runs a script and returns its stdout, or None when python3 is not on PATH
+fn run_python(source: &str) -> Option<String> {
+    let output = match Command::new("python3").args(["-c", source]).output() {
+        Ok(output) => output,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return None,
+        Err(error) => panic!("failed to run python3: {}", error),
+    };
+    assert!(output.status.success(), "python3 rejected the script:\n{}\n{}", String::from_utf8_lossy(&output.stderr), source);
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[test]
+fn test_python_constructor_becomes_init() {
+    let program = vec![Statement::ClassDeclaration {
+        token: token(TokenType::ClassKeyword),
+        name: "Counter".to_string(),
+        superclass: None,
+        mixins: vec![],
+        interfaces: vec![],
+        members: vec![
+            ClassMember::Field {
+                token: token(TokenType::Identifier("count".to_string())),
+                name: "count".to_string(),
+                type_name: Some("int".to_string()),
+                value: Some(int(0)),
+                visibility: Visibility::Public,
+                is_static: false,
+            },
+            ClassMember::Method {
+                token: token(TokenType::Identifier("constructor".to_string())),
+                name: "constructor".to_string(),
+                parameters: vec![("start".to_string(), TypeAnnotation::Named("int".to_string()))],
+                body: vec![Statement::Expression(Expression::Assignment {
+                    token: token(TokenType::Equals),
+                    left: Box::new(Expression::MemberAccess {
+                        token: token(TokenType::Dot),
+                        object: Box::new(Expression::This { token: token(TokenType::ThisKeyword) }),
+                        member: "count".to_string(),
+                    }),
+                    right: Box::new(ident("start")),
+                })],
+                return_type: None,
+                visibility: Visibility::Public,
+                is_static: false,
+                is_override: false,
+            },
+        ],
+    }];
+    let source = emit(&program);
+
+    let expected = "from __future__ import annotations
+
+class Counter:
+    count: int = 0
+    def __init__(self, start: int) -> None:
+        self.count = start
+";
+    assert_eq!(source, expected);
+    if let Some(output) = run_python(&format!("{}print(Counter(5).count)\n", source)) {
+        assert_eq!(output, "5\n");
+    }
+}
+
+#[test]
+fn test_python_int_division() {
+    let program = vec![function(
+        "halve",
+        &[("n", "int"), ("x", "float")],
+        Some("int"),
+        vec![
+            var("a", "int", binary(ident("n"), TokenType::Slash, int(2))),
+            var("b", "float", binary(ident("x"), TokenType::Slash, int(2))),
+            Statement::Expression(compound_assign("a", TokenType::SlashEquals, int(3))),
+            ret(ident("a")),
+        ],
+    )];
+    let source = emit(&program);
+
+    // `//` floors, so it matches the native targets' truncating division for non-negative operands
+    let expected = "def halve(n: int, x: float) -> int:
+    a = n // 2
+    b = x / 2
+    a //= 3
+    return a
+";
+    assert_eq!(source, expected);
+    if let Some(output) = run_python(&format!("{}print(halve(13, 1.0))\n", source)) {
+        assert_eq!(output, "2\n");
+    }
+}
+
+#[test]
+fn test_python_loops() {
+    let program = vec![
+        Statement::ForEachStatement {
+            token: token(TokenType::ForKeyword),
+            index_variable: None,
+            element_variable: LoopVariable::Simple("item".to_string(), None),
+            iterator: ident("items"),
+            body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call("print", vec![ident("item")]))])),
+        },
+        Statement::ForEachStatement {
+            token: token(TokenType::ForKeyword),
+            index_variable: Some("i".to_string()),
+            element_variable: LoopVariable::Simple("item".to_string(), None),
+            iterator: ident("items"),
+            body: Box::new(Statement::BlockStatement(vec![])),
+        },
+        Statement::ForStatement {
+            token: token(TokenType::ForKeyword),
+            initializer: Some(Box::new(var("i", "int", int(0)))),
+            condition: Some(binary(ident("i"), TokenType::LessThan, int(10))),
+            increment: Some(assign("i", binary(ident("i"), TokenType::Plus, int(1)))),
+            body: Box::new(Statement::BlockStatement(vec![if_else(
+                binary(binary(ident("i"), TokenType::Percent, int(2)), TokenType::EqualsEquals, int(0)),
+                vec![Statement::ContinueStatement { token: token(TokenType::ContinueKeyword) }],
+                None,
+            )])),
+        },
+        Statement::DoWhileStatement {
+            token: token(TokenType::DoKeyword),
+            body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call("tick", vec![]))])),
+            condition: binary(ident("n"), TokenType::GreaterThan, int(0)),
+        },
+        while_loop(boolean(false), vec![]),
+    ];
+
+    let expected = "for item in items:
+    print(item)
+for i, item in enumerate(items):
+    pass
+i = 0
+while i < 10:
+    if i % 2 == 0:
+        i = i + 1
+        continue
+    i = i + 1
+while True:
+    tick()
+    if not n > 0:
+        break
+while False:
+    pass
+";
+    assert_eq!(emit(&program), expected);
+}
+
+#[test]
+fn test_python_switch_and_indent_width() {
+    let program = vec![Statement::SwitchStatement {
+        token: token(TokenType::SwitchKeyword),
+        expression: call("level", vec![]),
+        cases: vec![
+            (
+                vec![int(1), int(2)],
+                vec![
+                    Statement::Expression(call("print", vec![string("low")])),
+                    Statement::BreakStatement { token: token(TokenType::BreakKeyword) },
+                ],
+            ),
+            (vec![int(3)], vec![Statement::BreakStatement { token: token(TokenType::BreakKeyword) }]),
+        ],
+        default: Some(vec![Statement::Expression(call("print", vec![string("high")]))]),
+    }];
+
+    let mut emitter = PythonEmitter::with_indent_width(2);
+    let source = emitter.emit(&program);
+
+    let expected = "_switch_1 = level()
+if _switch_1 == 1 or _switch_1 == 2:
+  print(\"low\")
+elif _switch_1 == 3:
+  pass
+else:
+  print(\"high\")
+";
+    assert_eq!(source, expected);
+}
+
+#[test]
+fn test_python_switch_braced_case_bodies() {
+    let program = vec![Statement::SwitchStatement {
+        token: token(TokenType::SwitchKeyword),
+        expression: ident("level"),
+        cases: vec![(
+            vec![int(1)],
+            vec![Statement::BlockStatement(vec![
+                Statement::Expression(call("print", vec![string("one")])),
+                Statement::BreakStatement { token: token(TokenType::BreakKeyword) },
+            ])],
+        )],
+        default: Some(vec![Statement::BlockStatement(vec![Statement::Expression(call("print", vec![string("other")]))])]),
+    }];
+    let source = emit(&program);
+
+    let expected = "if level == 1:
+    print(\"one\")
+else:
+    print(\"other\")
+";
+    assert_eq!(source, expected);
+}
+
+#[test]
+fn test_python_switch_rejects_fall_through_and_early_break() {
+    let print = |text: &str| Statement::Expression(call("print", vec![string(text)]));
+    let program = vec![Statement::SwitchStatement {
+        token: token(TokenType::SwitchKeyword),
+        expression: ident("level"),
+        cases: vec![
+            // Runs on into `case 2`
+            (vec![int(1)], vec![print("one")]),
+            (
+                vec![int(2)],
+                vec![
+                    if_else(ident("quiet"), vec![Statement::BreakStatement { token: token(TokenType::BreakKeyword) }], None),
+                    print("two"),
+                    Statement::BreakStatement { token: token(TokenType::BreakKeyword) },
+                ],
+            ),
+            // A break inside a loop leaves the loop, not the switch
+            (
+                vec![int(3)],
+                vec![while_loop(boolean(true), vec![Statement::BreakStatement { token: token(TokenType::BreakKeyword) }])],
+            ),
+        ],
+        default: None,
+    }];
+
+    let mut emitter = PythonEmitter::new();
+    emitter.emit(&program);
+
+    let errors = emitter.get_errors();
+    assert_eq!(errors.len(), 2, "unexpected errors: {:?}", errors);
+    assert!(errors.iter().any(|error| error.starts_with("Switch cases that fall through")));
+    assert!(errors.iter().any(|error| error.starts_with("Breaking out of a switch case early")));
+}
+
+#[test]
+fn test_python_delete() {
+    let delete = || Expression::Delete {
+        token: token(TokenType::DeleteKeyword),
+        target: Box::new(Expression::IndexAccess {
+            token: token(TokenType::LeftBracket),
+            array: Box::new(ident("scores")),
+            index: Box::new(string("bob")),
+        }),
+    };
+    assert_eq!(emit(&[Statement::Expression(delete())]), "del scores[\"bob\"]\n");
+
+    let mut emitter = PythonEmitter::new();
+    emitter.emit(&[var("removed", "bool", delete())]);
+    assert_eq!(emitter.get_errors().len(), 1);
+}
+
+#[test]
+fn test_python_super_call() {
+    let program = vec![Statement::Expression(Expression::SuperCall {
+        token: token(TokenType::SuperKeyword),
+        arguments: vec![ident("name"), int(1)],
+    })];
+    assert_eq!(emit(&program), "super().__init__(name, 1)\n");
+}
+
+#[test]
+fn test_python_for_in() {
+    let program = vec![Statement::ForInStatement {
+        token: token(TokenType::ForKeyword),
+        key_variable: "key".to_string(),
+        iterator: ident("scores"),
+        body: Box::new(Statement::BlockStatement(vec![Statement::Expression(call("print", vec![ident("key")]))])),
+    }];
+    assert_eq!(emit(&program), "for key in scores:\n    print(key)\n");
+}
\ No newline at end of file
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+
+use crate::ast::{Expression, LiteralValue, Statement, TypeAnnotation};
+use crate::codegen::compound_operator;
+use crate::token::TokenType;
+
+const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+const WASM_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+const SECTION_TYPE: u8 = 1;
+const SECTION_FUNCTION: u8 = 3;
+const SECTION_EXPORT: u8 = 7;
+const SECTION_CODE: u8 = 10;
+
+const TYPE_I32: u8 = 0x7f;
+const TYPE_FUNC: u8 = 0x60;
+const BLOCK_EMPTY: u8 = 0x40;
+const EXPORT_FUNC: u8 = 0x00;
+
+const OP_UNREACHABLE: u8 = 0x00;
+const OP_BLOCK: u8 = 0x02;
+const OP_LOOP: u8 = 0x03;
+const OP_IF: u8 = 0x04;
+const OP_ELSE: u8 = 0x05;
+const OP_END: u8 = 0x0b;
+const OP_BR: u8 = 0x0c;
+const OP_BR_IF: u8 = 0x0d;
+const OP_RETURN: u8 = 0x0f;
+const OP_CALL: u8 = 0x10;
+const OP_DROP: u8 = 0x1a;
+const OP_LOCAL_GET: u8 = 0x20;
+const OP_LOCAL_SET: u8 = 0x21;
+const OP_LOCAL_TEE: u8 = 0x22;
+const OP_I32_CONST: u8 = 0x41;
+const OP_I32_EQZ: u8 = 0x45;
+const OP_I32_EQ: u8 = 0x46;
+const OP_I32_NE: u8 = 0x47;
+const OP_I32_LT_S: u8 = 0x48;
+const OP_I32_GT_S: u8 = 0x4a;
+const OP_I32_LE_S: u8 = 0x4c;
+const OP_I32_GE_S: u8 = 0x4e;
+const OP_I32_ADD: u8 = 0x6a;
+const OP_I32_SUB: u8 = 0x6b;
+const OP_I32_MUL: u8 = 0x6c;
+const OP_I32_DIV_S: u8 = 0x6d;
+const OP_I32_REM_S: u8 = 0x6f;
+
+struct FunctionSignature {
+    index: u32,
+    parameter_count: usize,
+    returns_value: bool,
+}
+
+// Emits a binary WebAssembly module from a parsed program. Only top-level
+// function declarations over int and bool values are supported; every
+// function is exported under its own name.
+pub struct WasmEmitter {
+    functions: HashMap<String, FunctionSignature>,
+    locals: HashMap<String, u32>,
+    local_count: u32,
+    errors: Vec<String>,
+}
+
+impl WasmEmitter {
+    pub fn new() -> Self {
+        WasmEmitter {
+            functions: HashMap::new(),
+            locals: HashMap::new(),
+            local_count: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn get_errors(&self) -> &Vec<String> {
+        &self.errors
+    }
+
+    pub fn emit(&mut self, program: &[Statement]) -> Vec<u8> {
+        let mut declarations = Vec::new();
+
+        for statement in program {
+            match statement {
+                Statement::FunctionDeclaration { name, parameters, body, return_type, cleanup, .. } => {
+                    if cleanup.is_some() {
+                        self.errors.push(format!("Function '{}': finally blocks are not supported by the wasm target", name));
+                    }
+
+                    let signature = FunctionSignature {
+                        index: declarations.len() as u32,
+                        parameter_count: parameters.len(),
+                        returns_value: return_type.is_some(),
+                    };
+                    self.functions.insert(name.clone(), signature);
+                    declarations.push((name, parameters, body, return_type));
+                }
+                _ => self.errors.push("Only function declarations are supported at the top level by the wasm target".to_string()),
+            }
+        }
+
+        let mut types = Vec::new();
+        let mut function_indices = Vec::new();
+        let mut exports = Vec::new();
+        let mut bodies = Vec::new();
+
+        write_u32(&mut types, declarations.len() as u32);
+        write_u32(&mut function_indices, declarations.len() as u32);
+        write_u32(&mut exports, declarations.len() as u32);
+        write_u32(&mut bodies, declarations.len() as u32);
+
+        for (index, (name, parameters, body, return_type)) in declarations.into_iter().enumerate() {
+            types.push(TYPE_FUNC);
+            write_u32(&mut types, parameters.len() as u32);
+            for (parameter_name, type_annotation) in parameters {
+                self.check_type(parameter_name, type_annotation);
+                types.push(TYPE_I32);
+            }
+            match return_type {
+                Some(type_annotation) => {
+                    self.check_type(name, type_annotation);
+                    types.extend([1, TYPE_I32]);
+                }
+                None => types.push(0),
+            }
+
+            write_u32(&mut function_indices, index as u32);
+
+            write_name(&mut exports, name);
+            exports.push(EXPORT_FUNC);
+            write_u32(&mut exports, index as u32);
+
+            let function_body = self.emit_function_body(parameters, body, return_type.is_some());
+            write_u32(&mut bodies, function_body.len() as u32);
+            bodies.extend(function_body);
+        }
+
+        let mut module = Vec::new();
+        module.extend(WASM_MAGIC);
+        module.extend(WASM_VERSION);
+        write_section(&mut module, SECTION_TYPE, types);
+        write_section(&mut module, SECTION_FUNCTION, function_indices);
+        write_section(&mut module, SECTION_EXPORT, exports);
+        write_section(&mut module, SECTION_CODE, bodies);
+        module
+    }
+
+    fn emit_function_body(&mut self, parameters: &[(String, TypeAnnotation)], body: &[Statement], returns_value: bool) -> Vec<u8> {
+        self.locals.clear();
+        self.local_count = 0;
+
+        for (name, _) in parameters {
+            self.declare_local(name);
+        }
+
+        let mut code = Vec::new();
+        for statement in body {
+            self.emit_statement(statement, &mut code);
+        }
+        if returns_value {
+            // Falling off the end of a function that returns a value traps
+            code.push(OP_UNREACHABLE);
+        }
+        code.push(OP_END);
+
+        let declared_locals = self.local_count - parameters.len() as u32;
+        let mut function_body = Vec::new();
+        if declared_locals > 0 {
+            write_u32(&mut function_body, 1);
+            write_u32(&mut function_body, declared_locals);
+            function_body.push(TYPE_I32);
+        } else {
+            write_u32(&mut function_body, 0);
+        }
+        function_body.extend(code);
+        function_body
+    }
+
+    fn emit_statement(&mut self, statement: &Statement, code: &mut Vec<u8>) {
+        match statement {
+            Statement::VariableDeclaration { name, type_name, value, .. } => {
+                if let Some(type_annotation) = type_name {
+                    self.check_type(name, type_annotation);
+                }
+                let index = self.declare_local(name);
+                if let Some(value) = value {
+                    self.emit_value(value, code);
+                    code.push(OP_LOCAL_SET);
+                    write_u32(code, index);
+                }
+            }
+            Statement::ReturnStatement { value, .. } => {
+                if let Some(value) = value {
+                    self.emit_value(value, code);
+                }
+                code.push(OP_RETURN);
+            }
+            Statement::Expression(expression) => {
+                if self.emit_expression(expression, code) {
+                    code.push(OP_DROP);
+                }
+            }
+            Statement::IfStatement { condition, then_branch, else_branch, .. } => {
+                self.emit_value(condition, code);
+                code.extend([OP_IF, BLOCK_EMPTY]);
+                self.emit_statement(then_branch, code);
+                if let Some(else_branch) = else_branch {
+                    code.push(OP_ELSE);
+                    self.emit_statement(else_branch, code);
+                }
+                code.push(OP_END);
+            }
+            Statement::WhileStatement { condition, body, .. } => {
+                code.extend([OP_BLOCK, BLOCK_EMPTY, OP_LOOP, BLOCK_EMPTY]);
+                self.emit_value(condition, code);
+                code.extend([OP_I32_EQZ, OP_BR_IF, 1]);
+                self.emit_statement(body, code);
+                code.extend([OP_BR, 0, OP_END, OP_END]);
+            }
+            Statement::BlockStatement(statements) => {
+                for statement in statements {
+                    self.emit_statement(statement, code);
+                }
+            }
+            _ => self.errors.push("Unsupported statement in wasm target".to_string()),
+        }
+    }
+
+    // Emits an expression that must leave a value on the stack
+    fn emit_value(&mut self, expression: &Expression, code: &mut Vec<u8>) {
+        if !self.emit_expression(expression, code) {
+            self.errors.push("Expression does not produce a value".to_string());
+        }
+    }
+
+    // Returns whether the expression left a value on the stack
+    fn emit_expression(&mut self, expression: &Expression, code: &mut Vec<u8>) -> bool {
+        match expression {
+            Expression::Literal { value, .. } => match value {
+                LiteralValue::Int(value) => {
+                    code.push(OP_I32_CONST);
+                    write_i32(code, *value);
+                    true
+                }
+                LiteralValue::Bool(value) => {
+                    code.push(OP_I32_CONST);
+                    write_i32(code, *value as i32);
+                    true
+                }
+                _ => {
+                    self.errors.push("Only int and bool literals are supported by the wasm target".to_string());
+                    false
+                }
+            },
+            Expression::Identifier { name, .. } => match self.locals.get(name) {
+                Some(&index) => {
+                    code.push(OP_LOCAL_GET);
+                    write_u32(code, index);
+                    true
+                }
+                None => {
+                    self.errors.push(format!("Unknown variable '{}'", name));
+                    false
+                }
+            },
+            Expression::BinaryOperation { left, operator, right, .. } => {
+                match operator {
+                    // Short-circuit: the right operand only runs when needed
+                    TokenType::LogicalAnd => {
+                        self.emit_value(left, code);
+                        code.extend([OP_IF, TYPE_I32]);
+                        self.emit_value(right, code);
+                        code.extend([OP_ELSE, OP_I32_CONST, 0, OP_END]);
+                    }
+                    TokenType::LogicalOr => {
+                        self.emit_value(left, code);
+                        code.extend([OP_IF, TYPE_I32, OP_I32_CONST, 1, OP_ELSE]);
+                        self.emit_value(right, code);
+                        code.push(OP_END);
+                    }
+                    _ => {
+                        self.emit_value(left, code);
+                        self.emit_value(right, code);
+                        match binary_opcode(operator) {
+                            Some(opcode) => code.push(opcode),
+                            None => self.errors.push(format!("Unsupported binary operator {:?} in wasm target", operator)),
+                        }
+                    }
+                }
+                true
+            }
+            Expression::UnaryOperation { operator, operand, .. } => {
+                match operator {
+                    TokenType::Minus => {
+                        code.extend([OP_I32_CONST, 0]);
+                        self.emit_value(operand, code);
+                        code.push(OP_I32_SUB);
+                    }
+                    TokenType::LogicalNot => {
+                        self.emit_value(operand, code);
+                        code.push(OP_I32_EQZ);
+                    }
+                    _ => self.errors.push(format!("Unsupported unary operator {:?} in wasm target", operator)),
+                }
+                true
+            }
+            Expression::Assignment { token, left, right } => match &**left {
+                Expression::Identifier { name, .. } => {
+                    let index = match self.locals.get(name) {
+                        Some(&index) => index,
+                        None => {
+                            self.errors.push(format!("Unknown variable '{}'", name));
+                            return false;
+                        }
+                    };
+
+                    if token.token_type == TokenType::Equals {
+                        self.emit_value(right, code);
+                    } else {
+                        // `x += y` loads x, applies the operator, then stores the result
+                        match compound_operator(&token.token_type).as_ref().and_then(binary_opcode) {
+                            Some(opcode) => {
+                                code.push(OP_LOCAL_GET);
+                                write_u32(code, index);
+                                self.emit_value(right, code);
+                                code.push(opcode);
+                            }
+                            None => {
+                                self.errors.push(format!(
+                                    "Unsupported compound assignment {:?} in wasm target",
+                                    token.token_type
+                                ));
+                                return false;
+                            }
+                        }
+                    }
+
+                    code.push(OP_LOCAL_TEE);
+                    write_u32(code, index);
+                    true
+                }
+                _ => {
+                    self.errors.push("Only variables can be assigned to in the wasm target".to_string());
+                    false
+                }
+            },
+            Expression::FunctionCall { callee, arguments, .. } => {
+                let name = match &**callee {
+                    Expression::Identifier { name, .. } => name,
+                    _ => {
+                        self.errors.push("Only direct function calls are supported by the wasm target".to_string());
+                        return false;
+                    }
+                };
+
+                for argument in arguments {
+                    self.emit_value(argument, code);
+                }
+
+                match self.functions.get(name) {
+                    Some(signature) => {
+                        if signature.parameter_count != arguments.len() {
+                            self.errors.push(format!(
+                                "Function '{}' expects {} arguments but got {}",
+                                name,
+                                signature.parameter_count,
+                                arguments.len()
+                            ));
+                        }
+                        code.push(OP_CALL);
+                        write_u32(code, signature.index);
+                        signature.returns_value
+                    }
+                    None => {
+                        self.errors.push(format!("Unknown function '{}'", name));
+                        false
+                    }
+                }
+            }
+            Expression::Ternary { condition, then_expression, else_expression, .. } => {
+                self.emit_value(condition, code);
+                code.extend([OP_IF, TYPE_I32]);
+                self.emit_value(then_expression, code);
+                code.push(OP_ELSE);
+                self.emit_value(else_expression, code);
+                code.push(OP_END);
+                true
+            }
+            _ => {
+                self.errors.push("Unsupported expression in wasm target".to_string());
+                false
+            }
+        }
+    }
+
+    fn declare_local(&mut self, name: &str) -> u32 {
+        let index = self.local_count;
+        self.locals.insert(name.to_string(), index);
+        self.local_count += 1;
+        index
+    }
+
+    fn check_type(&mut self, name: &str, type_annotation: &TypeAnnotation) {
+        match type_annotation {
+            TypeAnnotation::Named(type_name) if type_name == "int" || type_name == "bool" => {}
+            _ => self.errors.push(format!("'{}': only int and bool types are supported by the wasm target", name)),
+        }
+    }
+}
+
+fn binary_opcode(operator: &TokenType) -> Option<u8> {
+    match operator {
+        TokenType::Plus => Some(OP_I32_ADD),
+        TokenType::Minus => Some(OP_I32_SUB),
+        TokenType::Star => Some(OP_I32_MUL),
+        TokenType::Slash => Some(OP_I32_DIV_S),
+        TokenType::Percent => Some(OP_I32_REM_S),
+        TokenType::EqualsEquals => Some(OP_I32_EQ),
+        TokenType::NotEquals => Some(OP_I32_NE),
+        TokenType::LessThan => Some(OP_I32_LT_S),
+        TokenType::GreaterThan => Some(OP_I32_GT_S),
+        TokenType::LessThanEquals => Some(OP_I32_LE_S),
+        TokenType::GreaterThanEquals => Some(OP_I32_GE_S),
+        _ => None,
+    }
+}
+
+fn write_u32(bytes: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn write_i32(bytes: &mut Vec<u8>, mut value: i32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn write_name(bytes: &mut Vec<u8>, name: &str) {
+    write_u32(bytes, name.len() as u32);
+    bytes.extend(name.as_bytes());
+}
+
+fn write_section(module: &mut Vec<u8>, id: u8, contents: Vec<u8>) {
+    module.push(id);
+    write_u32(module, contents.len() as u32);
+    module.extend(contents);
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+use crate::ast::{Expression, Statement};
+use crate::codegen::wasm::WasmEmitter;
+use crate::test_ast::*;
+use crate::token::TokenType;
+
+fn emit(program: &[Statement]) -> Vec<u8> {
+    let mut emitter = WasmEmitter::new();
+    let bytes = emitter.emit(program);
+    assert!(emitter.get_errors().is_empty(), "unexpected errors: {:?}", emitter.get_errors());
+    bytes
+}
+
+fn instantiate(bytes: &[u8]) -> (wasmi::Store<()>, wasmi::Instance) {
+    let engine = wasmi::Engine::default();
+    let module = wasmi::Module::new(&engine, bytes).expect("module should compile");
+    let mut store = wasmi::Store::new(&engine, ());
+    let linker = wasmi::Linker::<()>::new(&engine);
+    let instance = linker.instantiate(&mut store, &module).unwrap().start(&mut store).unwrap();
+    (store, instance)
+}
+
+fn add_function() -> Statement {
+    function(
+        "add",
+        &[("a", "int"), ("b", "int")],
+        Some("int"),
+        vec![ret(binary(ident("a"), TokenType::Plus, ident("b")))],
+    )
+}
+
+#[test]
+fn test_wasm_add_function_bytes() {
+    let bytes = emit(&[add_function()]);
+
+    let expected = vec![
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x07, 0x01, 0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7f, // type section
+        0x03, 0x02, 0x01, 0x00, // function section
+        0x07, 0x07, 0x01, 0x03, b'a', b'd', b'd', 0x00, 0x00, // export section
+        0x0a, 0x0b, 0x01, 0x09, 0x00, 0x20, 0x00, 0x20, 0x01, 0x6a, 0x0f, 0x00, 0x0b, // code section
+    ];
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn test_wasm_module_validates() {
+    let program = vec![
+        add_function(),
+        function(
+            "max",
+            &[("a", "int"), ("b", "int")],
+            Some("int"),
+            vec![ret(Expression::Ternary {
+                token: token(TokenType::QuestionMark),
+                condition: Box::new(binary(ident("a"), TokenType::GreaterThan, ident("b"))),
+                then_expression: Box::new(ident("a")),
+                else_expression: Box::new(ident("b")),
+            })],
+        ),
+        function("noop", &[], None, vec![Statement::Expression(call("add", vec![int(1), int(-200)]))]),
+    ];
+    let bytes = emit(&program);
+
+    wasmparser::Validator::new().validate_all(&bytes).expect("module should validate");
+}
+
+#[test]
+fn test_wasm_add_function_result() {
+    let bytes = emit(&[add_function()]);
+    let (mut store, instance) = instantiate(&bytes);
+
+    let add = instance.get_typed_func::<(i32, i32), i32>(&store, "add").unwrap();
+    assert_eq!(add.call(&mut store, (2, 3)).unwrap(), 5);
+    assert_eq!(add.call(&mut store, (-7, 4)).unwrap(), -3);
+}
+
+#[test]
+fn test_wasm_calls_locals_and_loops() {
+    // function square(int n): int { return n * n; }
+    // function sum_of_squares(int n): int {
+    //     int total = 0;
+    //     int i = 1;
+    //     while (i <= n) { total = total + square(i); i = i + 1; }
+    //     if (total > 1000 && n > 0) { return 1000; }
+    //     return total;
+    // }
+    let program = vec![
+        function("square", &[("n", "int")], Some("int"), vec![ret(binary(ident("n"), TokenType::Star, ident("n")))]),
+        function(
+            "sum_of_squares",
+            &[("n", "int")],
+            Some("int"),
+            vec![
+                var("total", "int", int(0)),
+                var("i", "int", int(1)),
+                while_loop(
+                    binary(ident("i"), TokenType::LessThanEquals, ident("n")),
+                    vec![
+                        Statement::Expression(assign(
+                            "total",
+                            binary(ident("total"), TokenType::Plus, call("square", vec![ident("i")])),
+                        )),
+                        Statement::Expression(assign("i", binary(ident("i"), TokenType::Plus, int(1)))),
+                    ],
+                ),
+                if_else(
+                    binary(
+                        binary(ident("total"), TokenType::GreaterThan, int(1000)),
+                        TokenType::LogicalAnd,
+                        binary(ident("n"), TokenType::GreaterThan, int(0)),
+                    ),
+                    vec![ret(int(1000))],
+                    None,
+                ),
+                ret(ident("total")),
+            ],
+        ),
+    ];
+    let bytes = emit(&program);
+    let (mut store, instance) = instantiate(&bytes);
+
+    let sum_of_squares = instance.get_typed_func::<i32, i32>(&store, "sum_of_squares").unwrap();
+    assert_eq!(sum_of_squares.call(&mut store, 3).unwrap(), 14);
+    assert_eq!(sum_of_squares.call(&mut store, 0).unwrap(), 0);
+    assert_eq!(sum_of_squares.call(&mut store, 20).unwrap(), 1000);
+}
+
+#[test]
+fn test_wasm_compound_assignment() {
+    // function bump(int x): int { x += 1; x *= 3; return x; }
+    let program = vec![function(
+        "bump",
+        &[("x", "int")],
+        Some("int"),
+        vec![
+            Statement::Expression(compound_assign("x", TokenType::PlusEquals, int(1))),
+            Statement::Expression(compound_assign("x", TokenType::StarEquals, int(3))),
+            ret(ident("x")),
+        ],
+    )];
+    let bytes = emit(&program);
+    let (mut store, instance) = instantiate(&bytes);
+
+    let bump = instance.get_typed_func::<i32, i32>(&store, "bump").unwrap();
+    assert_eq!(bump.call(&mut store, 4).unwrap(), 15);
+    assert_eq!(bump.call(&mut store, -1).unwrap(), 0);
+}
+
+#[test]
+fn test_wasm_reports_unsupported_code() {
+    let program = vec![
+        var("x", "int", int(1)),
+        function("f", &[("s", "string")], Some("int"), vec![ret(call("missing", vec![boolean(true)]))]),
+    ];
+    let mut emitter = WasmEmitter::new();
+    emitter.emit(&program);
+
+    let errors = emitter.get_errors();
+    assert!(errors.iter().any(|error| error.contains("top level")));
+    assert!(errors.iter().any(|error| error.contains("'s': only int and bool")));
+    assert!(errors.iter().any(|error| error.contains("Unknown function 'missing'")));
+}
\ No newline at end of file
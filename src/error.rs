@@ -1,6 +1,32 @@
+use std::error::Error;
+use std::fmt;
+
 #[derive(Debug)]
 pub struct LexerError {
     pub message: String,
     pub line: usize,
     pub column: usize,
-}
\ No newline at end of file
+}
+
+impl fmt::Display for LexerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error at {}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for LexerError {}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error at {}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
\ No newline at end of file
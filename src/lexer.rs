@@ -1,9 +1,13 @@
+use std::fs;
+use std::io;
+use std::path::Path;
 use regex::Regex;
 use crate::token::{Token, TokenType};
 use crate::error::LexerError;
 
 pub struct Lexer {
     pub source: String,
+    pub source_path: Option<String>,
     pub current_char: Option<char>,
     pub current_position: usize,
     pub line: usize,
@@ -16,6 +20,7 @@ impl Lexer {
     pub fn new(source: &str) -> Self {
         let mut lexer = Lexer {
             source: source.to_string(),
+            source_path: None,
             current_char: Some(source.chars().next().unwrap_or('\0')),
             current_position: 0,
             line: 1,
@@ -27,17 +32,28 @@ impl Lexer {
         lexer
     }
 
+    pub fn from_file(path: &Path) -> Result<Self, io::Error> {
+        let source = fs::read_to_string(path)?;
+        let mut lexer = Lexer::new(&source);
+        lexer.source_path = Some(path.display().to_string());
+        Ok(lexer)
+    }
+
     pub fn tokenize(&mut self) {
         // Define regular expressions for literals
         let int_regex = Regex::new(r"^\d+").unwrap();
         let float_regex = Regex::new(r"^\d+\.\d+").unwrap();
         let string_regex = Regex::new(r#"^"([^"\\]|\\.)*""#).unwrap(); // Supports escaped quotes
+        let raw_string_regex = Regex::new(r#"^r"[^"]*""#).unwrap(); // No escapes, may span lines
         // Regex for single-line comments
         let single_line_comment_regex = Regex::new(r"//.*").unwrap();
         // Regex for multi-line comments
         let multi_line_comment_regex = Regex::new(r"/\*[\s\S]*?\*/").unwrap();
 
         while let Some(c) = self.current_char {
+            let token_start = self.current_position;
+            let token_count = self.tokens.len();
+
             match c {
                 ' ' | '\t' => self.consume(),
                 '\n' => {
@@ -162,9 +178,9 @@ impl Lexer {
                         self.consume();
                         self.add_token(TokenType::LogicalOr);
                     } else {
-                        self.error("Invalid character '|'".to_string());
-                        self.consume();
+                        self.add_token(TokenType::Pipe);
                     }
+                    self.consume();
                 }
                 ';' => {
                     self.add_token(TokenType::Semicolon);
@@ -178,6 +194,10 @@ impl Lexer {
                     self.add_token(TokenType::Colon);
                     self.consume();
                 }
+                '?' => {
+                    self.add_token(TokenType::QuestionMark);
+                    self.consume();
+                }
                 '.' => {
                     self.add_token(TokenType::Dot);
                     self.consume();
@@ -216,6 +236,7 @@ impl Lexer {
                     self.consume();
                 }
                 '\"' => self.string(&string_regex),
+                'r' if self.peek() == Some('"') => self.raw_string(&raw_string_regex),
                 '0'..='9' => self.number(&int_regex, &float_regex),
                 _ if self.is_valid_identifier_start(c) => self.identifier(),
                 _ => {
@@ -223,22 +244,37 @@ impl Lexer {
                     self.consume();
                 }
             }
+
+            // Every arm consumes its whole lexeme, so the token ends where we are now
+            if let Some(token) = self.tokens.get_mut(token_count) {
+                token.start_offset = token_start;
+                token.end_offset = self.current_position;
+            }
         }
         self.add_token(TokenType::EOF);
     }
 
     fn consume(&mut self) {
-        self.current_position += 1;
+        // Step over the whole character so we never land inside a UTF-8 sequence
+        self.current_position += self.current_char.map_or(1, |c| c.len_utf8());
         self.column += 1;
         self.current_char = self.source[self.current_position..].chars().next();
     }
 
     fn peek(&self) -> Option<char> {
-        self.source[self.current_position + 1..].chars().next()
+        self.peek_n(1)
+    }
+
+    // The character `n` characters past the current one, counted in chars so
+    // it never slices inside a UTF-8 sequence
+    fn peek_n(&self, n: usize) -> Option<char> {
+        self.source[self.current_position..].chars().nth(n)
     }
 
     fn add_token(&mut self, token_type: TokenType) {
-        let token = Token::new(token_type, self.line, self.column);
+        let mut token = Token::new(token_type, self.line, self.column);
+        token.start_offset = self.current_position;
+        token.end_offset = self.current_position;
         self.tokens.push(token);
     }
 
@@ -265,6 +301,23 @@ impl Lexer {
         }
     }
 
+    fn raw_string(&mut self, regex: &Regex) {
+        let remaining_source = &self.source[self.current_position..];
+        if let Some(mat) = regex.find(remaining_source) {
+            // Owned, so the borrow of `self.source` ends before consuming
+            let string_literal = mat.as_str().to_string();
+            self.consume_matched_string(&string_literal);
+            // Remove the `r"` prefix and closing quote, keeping backslashes as written
+            let value = string_literal[2..string_literal.len() - 1].to_string();
+            self.add_token(TokenType::String(value));
+        } else {
+            // Nothing after the opening quote can close it, so skip the rest of the source
+            let rest = remaining_source.to_string();
+            self.error("Unterminated raw string literal".to_string());
+            self.consume_matched_string(&rest);
+        }
+    }
+
     fn process_escape_sequences(&self, input: &str) -> String {
         let mut result = String::new();
         let mut chars = input.chars();
@@ -351,6 +404,7 @@ impl Lexer {
             "while" => TokenType::WhileKeyword,
             "for" => TokenType::ForKeyword,
             "of" => TokenType::OfKeyword,
+            "in" => TokenType::InKeyword,
             "switch" => TokenType::SwitchKeyword,
             "case" => TokenType::CaseKeyword,
             "break" => TokenType::BreakKeyword,
@@ -373,6 +427,18 @@ impl Lexer {
             "default" => TokenType::DefaultKeyword,
             "new" => TokenType::NewKeyword,
             "this" => TokenType::ThisKeyword,
+            "super" => TokenType::SuperKeyword,
+            "clone" => TokenType::CloneKeyword,
+            "finally" => TokenType::FinallyKeyword,
+            "as" => TokenType::AsKeyword,
+            "overrides" => TokenType::OverridesKeyword,
+            "defer" => TokenType::DeferKeyword,
+            "cancel" => TokenType::CancelKeyword,
+            "struct" => TokenType::StructKeyword,
+            "union" => TokenType::UnionKeyword,
+            "with" => TokenType::WithKeyword,
+            "delete" => TokenType::DeleteKeyword,
+            "freeze" => TokenType::FreezeKeyword,
             _ => TokenType::Identifier(identifier),
         };
 
@@ -0,0 +1,155 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use crate::error::LexerError;
+use crate::lexer::Lexer;
+use crate::token::TokenType;
+
+#[test]
+fn test_lexer_error_display() {
+    let error = LexerError {
+        message: "Unterminated string literal".to_string(),
+        line: 3,
+        column: 14,
+    };
+
+    assert_eq!(
+        format!("{}", error),
+        "error at 3:14: Unterminated string literal"
+    );
+}
+
+#[test]
+fn test_lexer_from_file() {
+    let path = env::temp_dir().join("jal_lexer_from_file.jal");
+    fs::write(&path, "int x = 5;").unwrap();
+
+    let lexer = Lexer::from_file(&path).unwrap();
+
+    assert_eq!(lexer.source, "int x = 5;");
+    assert_eq!(lexer.source_path, Some(path.display().to_string()));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_lexer_from_missing_file() {
+    let result = Lexer::from_file(Path::new("does/not/exist.jal"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_token_lexeme_matches_identifier() {
+    let source = " int total = price * count;";
+    let mut lexer = Lexer::new(source);
+    lexer.tokenize();
+
+    let mut identifiers = 0;
+    for token in &lexer.tokens {
+        if let TokenType::Identifier(name) = &token.token_type {
+            assert_eq!(token.lexeme(source), name);
+            identifiers += 1;
+        }
+    }
+    assert_eq!(identifiers, 3);
+}
+
+#[test]
+fn test_token_lexeme_operators_and_literals() {
+    let source = " x += 42;";
+    let mut lexer = Lexer::new(source);
+    lexer.tokenize();
+
+    let lexemes: Vec<&str> = lexer
+        .tokens
+        .iter()
+        .filter(|token| token.token_type != TokenType::EOF)
+        .map(|token| token.lexeme(source))
+        .collect();
+
+    assert_eq!(lexemes, vec!["x", "+=", "42", ";"]);
+}
+
+#[test]
+fn test_lexer_multi_byte_characters() {
+    let source = " x ≥≥ y => \"héllo ≥\" é;";
+    let mut lexer = Lexer::new(source);
+    lexer.tokenize();
+
+    assert_eq!(lexer.errors.len(), 2); // one per stray '≥'
+    assert!(lexer
+        .tokens
+        .iter()
+        .any(|token| token.token_type == TokenType::EqualsGreaterThan));
+    assert!(lexer
+        .tokens
+        .iter()
+        .any(|token| token.token_type == TokenType::String("héllo ≥".to_string())));
+    assert!(lexer
+        .tokens
+        .iter()
+        .any(|token| token.token_type == TokenType::Identifier("é".to_string())));
+}
+
+#[test]
+fn test_lexer_peek_before_multi_byte_character() {
+    let source = " =≥";
+    let mut lexer = Lexer::new(source);
+    lexer.tokenize();
+
+    assert_eq!(lexer.tokens[0].token_type, TokenType::Equals);
+    assert_eq!(lexer.errors.len(), 1);
+}
+
+#[test]
+fn test_lexer_raw_string_skips_escapes() {
+    let mut lexer = Lexer::new(r#" r"\n" r"C:\temp\" "\n""#);
+    lexer.tokenize();
+
+    assert!(lexer.errors.is_empty());
+    assert_eq!(lexer.tokens[0].token_type, TokenType::String("\\n".to_string()));
+    match &lexer.tokens[0].token_type {
+        TokenType::String(value) => assert_eq!(value.len(), 2),
+        _ => panic!("Expected string token"),
+    }
+    assert_eq!(lexer.tokens[1].token_type, TokenType::String("C:\\temp\\".to_string()));
+    assert_eq!(lexer.tokens[2].token_type, TokenType::String("\n".to_string()));
+}
+
+#[test]
+fn test_lexer_raw_string_matches_plain_string() {
+    let mut raw = Lexer::new(r#" r"hello""#);
+    raw.tokenize();
+    let mut plain = Lexer::new(r#" "hello""#);
+    plain.tokenize();
+
+    assert_eq!(raw.tokens[0].token_type, plain.tokens[0].token_type);
+}
+
+#[test]
+fn test_lexer_raw_string_spans_lines() {
+    let mut lexer = Lexer::new(" r\"one\ntwo\" x");
+    lexer.tokenize();
+
+    assert_eq!(lexer.tokens[0].token_type, TokenType::String("one\ntwo".to_string()));
+    assert_eq!(lexer.tokens[1].line, 2);
+}
+
+#[test]
+fn test_lexer_unterminated_raw_string() {
+    let mut lexer = Lexer::new(" r\"open");
+    lexer.tokenize();
+
+    assert_eq!(lexer.errors.len(), 1);
+    assert_eq!(lexer.errors[0].message, "Unterminated raw string literal");
+}
+
+#[test]
+fn test_lexer_identifier_starting_with_r() {
+    let mut lexer = Lexer::new(" return r");
+    lexer.tokenize();
+
+    assert_eq!(lexer.tokens[0].token_type, TokenType::ReturnKeyword);
+    assert_eq!(lexer.tokens[1].token_type, TokenType::Identifier("r".to_string()));
+}
\ No newline at end of file
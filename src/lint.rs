@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::ast::{ClassMember, ExportSpecifier, Expression, LiteralValue, Statement};
+
+#[derive(Debug, PartialEq)]
+pub enum LintWarning {
+    InfiniteLoop { line: usize, column: usize },
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LintWarning::InfiniteLoop { line, column } => {
+                write!(f, "warning at {}:{}: loop never exits", line, column)
+            }
+        }
+    }
+}
+
+// Walks a parsed program looking for code that is valid but almost
+// certainly a mistake. Warnings never stop compilation.
+pub struct Linter {
+    warnings: Vec<LintWarning>,
+}
+
+impl Linter {
+    pub fn new() -> Self {
+        Linter { warnings: Vec::new() }
+    }
+
+    pub fn get_warnings(&self) -> &Vec<LintWarning> {
+        &self.warnings
+    }
+
+    pub fn lint(&mut self, program: &[Statement]) {
+        for statement in program {
+            self.lint_statement(statement);
+        }
+    }
+
+    fn lint_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::WhileStatement { token, condition, body } => {
+                let always_true =
+                    matches!(condition, Expression::Literal { value: LiteralValue::Bool(true), .. });
+                if always_true && !Self::leaves_loop(body, true) {
+                    self.warnings.push(LintWarning::InfiniteLoop { line: token.line, column: token.column });
+                }
+                self.lint_statement(body);
+            }
+            Statement::FunctionDeclaration { body, cleanup, .. } => {
+                self.lint(body);
+                if let Some(cleanup) = cleanup {
+                    self.lint(cleanup);
+                }
+            }
+            Statement::IfStatement { then_branch, else_branch, .. } => {
+                self.lint_statement(then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.lint_statement(else_branch);
+                }
+            }
+            Statement::DoWhileStatement { body, .. }
+            | Statement::ForStatement { body, .. }
+            | Statement::ForEachStatement { body, .. }
+            | Statement::ForInStatement { body, .. } => self.lint_statement(body),
+            Statement::ClassDeclaration { members, .. } => {
+                for member in members {
+                    match member {
+                        ClassMember::Method { body, .. } | ClassMember::StaticInitializer { body, .. } => {
+                            self.lint(body)
+                        }
+                        ClassMember::Field { .. } | ClassMember::AbstractMethod { .. } => {}
+                    }
+                }
+            }
+            Statement::SwitchStatement { cases, default, .. } => {
+                for (_, body) in cases {
+                    self.lint(body);
+                }
+                if let Some(default) = default {
+                    self.lint(default);
+                }
+            }
+            Statement::ExportDeclaration { specifiers, .. } => {
+                for specifier in specifiers {
+                    if let ExportSpecifier::Default(statement) = specifier {
+                        self.lint_statement(statement);
+                    }
+                }
+            }
+            Statement::BlockStatement(statements) => self.lint(statements),
+            _ => {}
+        }
+    }
+
+    // Whether `statement` can leave the enclosing loop. A `break` only counts
+    // when it is not nested inside another loop or a switch.
+    fn leaves_loop(statement: &Statement, break_exits: bool) -> bool {
+        match statement {
+            Statement::BreakStatement { .. } => break_exits,
+            Statement::ReturnStatement { .. } => true,
+            Statement::IfStatement { then_branch, else_branch, .. } => {
+                Self::leaves_loop(then_branch, break_exits)
+                    || else_branch.as_ref().is_some_and(|branch| Self::leaves_loop(branch, break_exits))
+            }
+            Statement::WhileStatement { body, .. }
+            | Statement::DoWhileStatement { body, .. }
+            | Statement::ForStatement { body, .. }
+            | Statement::ForEachStatement { body, .. }
+            | Statement::ForInStatement { body, .. } => Self::leaves_loop(body, false),
+            Statement::SwitchStatement { cases, default, .. } => cases
+                .iter()
+                .map(|(_, body)| body)
+                .chain(default)
+                .flatten()
+                .any(|statement| Self::leaves_loop(statement, false)),
+            Statement::BlockStatement(statements) => {
+                statements.iter().any(|statement| Self::leaves_loop(statement, break_exits))
+            }
+            _ => false,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use crate::ast::Statement;
+use crate::lint::{LintWarning, Linter};
+use crate::test_ast::*;
+use crate::token::{Token, TokenType};
+
+fn lint(program: &[Statement]) -> Linter {
+    let mut linter = Linter::new();
+    linter.lint(program);
+    linter
+}
+
+fn brk() -> Statement {
+    Statement::BreakStatement { token: token(TokenType::BreakKeyword) }
+}
+
+#[test]
+fn test_lint_empty_while_true_is_infinite() {
+    let program = vec![Statement::WhileStatement {
+        token: Token::new(TokenType::WhileKeyword, 3, 5),
+        condition: boolean(true),
+        body: Box::new(Statement::BlockStatement(vec![])),
+    }];
+    let linter = lint(&program);
+
+    assert_eq!(linter.get_warnings(), &vec![LintWarning::InfiniteLoop { line: 3, column: 5 }]);
+    assert_eq!(linter.get_warnings()[0].to_string(), "warning at 3:5: loop never exits");
+}
+
+#[test]
+fn test_lint_while_true_with_break() {
+    let program = vec![
+        while_loop(boolean(true), vec![brk()]),
+        while_loop(boolean(true), vec![if_else(ident("done"), vec![brk()], None)]),
+    ];
+    assert!(lint(&program).get_warnings().is_empty());
+}
+
+#[test]
+fn test_lint_while_true_with_return() {
+    let program = vec![function(
+        "wait",
+        &[],
+        Some("int"),
+        vec![while_loop(boolean(true), vec![while_loop(ident("busy"), vec![ret(int(1))])])],
+    )];
+    assert!(lint(&program).get_warnings().is_empty());
+}
+
+#[test]
+fn test_lint_break_in_nested_loop_does_not_count() {
+    let program = vec![
+        while_loop(boolean(true), vec![while_loop(ident("busy"), vec![brk()])]),
+        while_loop(
+            boolean(true),
+            vec![Statement::SwitchStatement {
+                token: token(TokenType::SwitchKeyword),
+                expression: ident("state"),
+                cases: vec![(vec![int(0)], vec![brk()])],
+                default: None,
+            }],
+        ),
+    ];
+    assert_eq!(lint(&program).get_warnings().len(), 2);
+}
+
+#[test]
+fn test_lint_only_checks_literal_true() {
+    let program = vec![
+        while_loop(ident("running"), vec![]),
+        while_loop(boolean(false), vec![]),
+        function("spin", &[], None, vec![while_loop(boolean(true), vec![])]),
+    ];
+    assert_eq!(lint(&program).get_warnings().len(), 1);
+}
\ No newline at end of file
@@ -1,41 +1,129 @@
 use std::env;
-use std::fs::File;
-use std::io::prelude::*;
+use std::fs;
+use std::path::Path;
 
 mod lexer;
 mod token;
 mod error;
 mod ast;
 mod parser;
+mod codegen;
+mod lint;
+mod semantic;
 mod parser_tests;
+#[cfg(test)]
+mod lexer_tests;
+#[cfg(test)]
+mod lint_tests;
+#[cfg(test)]
+mod semantic_tests;
+#[cfg(test)]
+mod test_ast;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() != 2 {
-        println!("Usage: cargo run <filename>");
+    let target = match args.len() {
+        2 => None,
+        4 if args[2] == "--target" => Some(args[3].as_str()),
+        _ => {
+            println!("Usage: cargo run <filename> [--target wasm|llvm-ir|js|python]");
+            return;
+        }
+    };
+
+    let filename = Path::new(&args[1]);
+    let mut lexer = lexer::Lexer::from_file(filename).expect("Something went wrong reading the file");
+    lexer.tokenize();
+
+    let source_path = lexer.source_path.clone().unwrap_or_else(|| "<unknown>".to_string());
+    if !lexer.errors.is_empty() {
+        for error in &lexer.errors {
+            eprintln!("{}: Lexer {}", source_path, error);
+        }
         return;
     }
 
-    let filename = &args[1];
-    let mut file = File::open(filename).expect("File not found");
-
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .expect("Something went wrong reading the file");
+    let target = match target {
+        Some(target) => target,
+        None => {
+            // Print tokens for now
+            for token in lexer.tokens {
+                println!("{:?}", token);
+            }
+            return;
+        }
+    };
 
-    let mut lexer = lexer::Lexer::new(&contents);
-    lexer.tokenize();
+    let mut parser = parser::Parser::new(lexer);
+    let program = parser.parse_program();
+    if !parser.get_errors().is_empty() {
+        for error in parser.get_errors() {
+            eprintln!("{}: Parser {}", source_path, error);
+        }
+        return;
+    }
 
-    if !lexer.errors.is_empty() {
-        for error in lexer.errors {
-            eprintln!("Lexer error: {} at line: {}, column: {}", error.message, error.line, error.column);
+    let mut analyzer = semantic::Analyzer::new();
+    analyzer.analyze(&program);
+    if !analyzer.get_errors().is_empty() {
+        for error in analyzer.get_errors() {
+            eprintln!("{}: Semantic error: {}", source_path, error);
         }
         return;
     }
 
-    // Print tokens for now
-    for token in lexer.tokens {
-        println!("{:?}", token);
+    let mut linter = lint::Linter::new();
+    linter.lint(&program);
+    for warning in linter.get_warnings() {
+        eprintln!("{}: Lint {}", source_path, warning);
+    }
+
+    match target {
+        "wasm" => {
+            let mut emitter = codegen::wasm::WasmEmitter::new();
+            let bytes = emitter.emit(&program);
+            if !emitter.get_errors().is_empty() {
+                for error in emitter.get_errors() {
+                    eprintln!("{}: Codegen error: {}", source_path, error);
+                }
+                return;
+            }
+            fs::write(filename.with_extension("wasm"), bytes).expect("Something went wrong writing the output file");
+        }
+        "llvm-ir" => {
+            let mut emitter = codegen::llvmir::LlvmIrEmitter::new();
+            let ir = emitter.emit(&program);
+            if !emitter.get_errors().is_empty() {
+                for error in emitter.get_errors() {
+                    eprintln!("{}: Codegen error: {}", source_path, error);
+                }
+                return;
+            }
+            fs::write(filename.with_extension("ll"), ir).expect("Something went wrong writing the output file");
+        }
+        "js" => {
+            let mut emitter = codegen::js::JsEmitter::new();
+            let source = emitter.emit(&program);
+            if !emitter.get_errors().is_empty() {
+                for error in emitter.get_errors() {
+                    eprintln!("{}: Codegen error: {}", source_path, error);
+                }
+                return;
+            }
+            fs::write(filename.with_extension("js"), source).expect("Something went wrong writing the output file");
+        }
+        "python" => {
+            let mut emitter = codegen::python::PythonEmitter::new();
+            let source = emitter.emit(&program);
+            if !emitter.get_errors().is_empty() {
+                for error in emitter.get_errors() {
+                    eprintln!("{}: Codegen error: {}", source_path, error);
+                }
+                return;
+            }
+            fs::write(filename.with_extension("py"), source).expect("Something went wrong writing the output file");
+        }
+        _ => println!("Unknown target '{}'", target),
     }
 }
\ No newline at end of file
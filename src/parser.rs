@@ -1,15 +1,17 @@
 use crate::token::{TokenType, Token};
 use crate::ast::{
     LiteralValue, Statement, Expression, Visibility, ClassMember, InterfaceMember, ImportSpecifier,
-    ExportSpecifier,
+    ExportSpecifier, TypeAnnotation, ObjectProperty, LoopVariable,
 };
 use crate::lexer::Lexer;
+use crate::error::ParseError;
+use std::mem;
 
 pub struct Parser {
     lexer: Lexer,
     current_token: Token,
     peek_token: Token,
-    errors: Vec<String>,
+    errors: Vec<ParseError>,
 }
 
 impl Parser {
@@ -25,7 +27,7 @@ impl Parser {
         parser
     }
 
-    pub fn get_errors(&self) -> &Vec<String> {
+    pub fn get_errors(&self) -> &Vec<ParseError> {
         &self.errors
     }
 
@@ -51,15 +53,34 @@ impl Parser {
             token_type,
             self.peek_token.token_type
         );
-        self.errors.push(msg);
+        self.error_at_peek(msg);
     }
 
+    fn error(&mut self, message: String) {
+        let error = ParseError {
+            message,
+            line: self.current_token.line,
+            column: self.current_token.column,
+        };
+        self.errors.push(error);
+    }
+
+    fn error_at_peek(&mut self, message: String) {
+        let error = ParseError {
+            message,
+            line: self.peek_token.line,
+            column: self.peek_token.column,
+        };
+        self.errors.push(error);
+    }
+
+    // Compares variants only, so `TokenType::Identifier(String::new())` matches any identifier
     fn peek_token_is(&self, token_type: TokenType) -> bool {
-        self.peek_token.token_type == token_type
+        mem::discriminant(&self.peek_token.token_type) == mem::discriminant(&token_type)
     }
 
     fn current_token_is(&self, token_type: TokenType) -> bool {
-        self.current_token.token_type == token_type
+        mem::discriminant(&self.current_token.token_type) == mem::discriminant(&token_type)
     }
 
     pub fn parse_program(&mut self) -> Vec<Statement> {
@@ -89,9 +110,9 @@ impl Parser {
             TokenType::DoKeyword => self.parse_do_while_statement(),
             TokenType::WhileKeyword => self.parse_while_statement(),
             TokenType::ForKeyword => {
-                if self.peek_token_is(TokenType::Identifier(String::new()))
-                    && self.lexer.tokens.get(1).map_or(false, |t| t.token_type == TokenType::OfKeyword)
-                {
+                if self.is_for_in_loop() {
+                    self.parse_for_in_statement()
+                } else if self.is_for_of_loop() {
                     self.parse_for_of_statement() // Call the new parsing function
                 } else {
                     self.parse_for_statement() // Parse the standard for loop
@@ -101,11 +122,14 @@ impl Parser {
             TokenType::ContinueKeyword => self.parse_continue_statement(),
             TokenType::EnumKeyword => self.parse_enum_declaration(),
             TokenType::ObjectKeyword => self.parse_object_declaration(),
+            TokenType::StructKeyword => self.parse_struct_declaration(),
+            TokenType::UnionKeyword => self.parse_union_declaration(),
             TokenType::ClassKeyword => self.parse_class_declaration(),
             TokenType::InterfaceKeyword => self.parse_interface_declaration(),
             TokenType::ImportKeyword => self.parse_import_declaration(),
             TokenType::ExportKeyword => self.parse_export_declaration(),
             TokenType::SwitchKeyword => self.parse_switch_statement(),
+            TokenType::CancelKeyword => self.parse_cancel_statement(),
             _ => {
                 // For expressions, attempt to parse them.
                 // If there's an error, skip to the next semicolon and report it.
@@ -138,13 +162,7 @@ impl Parser {
 
     fn parse_variable_declaration(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
-        let type_name = match self.current_token.token_type {
-            TokenType::IntKeyword => Some("int".to_string()),
-            TokenType::FloatKeyword => Some("float".to_string()),
-            TokenType::StringKeyword => Some("string".to_string()),
-            TokenType::BoolKeyword => Some("bool".to_string()),
-            _ => None,
-        };
+        let type_name = Some(self.parse_type_annotation()?);
 
         if !self.expect_peek(TokenType::Identifier(String::new())) {
             return None;
@@ -177,28 +195,17 @@ impl Parser {
         let token = self.current_token.clone(); // "const" token
 
         // Expect a type keyword after "const"
-        let type_name = match self.peek_token.token_type {
-            TokenType::IntKeyword => {
-                self.next_token(); // Consume the type keyword
-                Some("int".to_string())
-            }
-            TokenType::FloatKeyword => {
-                self.next_token();
-                Some("float".to_string())
-            }
-            TokenType::StringKeyword => {
-                self.next_token();
-                Some("string".to_string())
-            }
-            TokenType::BoolKeyword => {
-                self.next_token();
-                Some("bool".to_string())
-            }
+        match self.peek_token.token_type {
+            TokenType::IntKeyword
+            | TokenType::FloatKeyword
+            | TokenType::StringKeyword
+            | TokenType::BoolKeyword => self.next_token(), // Consume "const"
             _ => {
                 self.peek_error(TokenType::IntKeyword); // Or any other valid type keyword
                 return None;
             }
-        };
+        }
+        let type_name = Some(self.parse_type_annotation()?);
 
         if !self.expect_peek(TokenType::Identifier(String::new())) {
             return None;
@@ -250,14 +257,9 @@ impl Parser {
         }
 
         let return_type = if self.peek_token_is(TokenType::EqualsGreaterThan) {
+            self.next_token(); // consume ')'
             self.next_token(); // consume '=>'
-            if !self.expect_peek(TokenType::Identifier(String::new())) {
-                return None;
-            }
-            match self.current_token.token_type.clone() {
-                TokenType::Identifier(identifier) => Some(identifier),
-                _ => unreachable!(),
-            }
+            Some(self.parse_type_annotation()?)
         } else {
             None
         };
@@ -266,11 +268,19 @@ impl Parser {
             return None;
         }
 
+        // parse_block_statement stops on the closing '}'
         let body = self.parse_block_statement();
 
-        if !self.expect_peek(TokenType::RightBrace) {
-            return None;
-        }
+        // Optional `finally { ... }` that runs on every exit path of the function
+        let cleanup = if self.peek_token_is(TokenType::FinallyKeyword) {
+            self.next_token(); // consume '}'
+            if !self.expect_peek(TokenType::LeftBrace) {
+                return None;
+            }
+            Some(self.parse_block_statement())
+        } else {
+            None
+        };
 
         Some(Statement::FunctionDeclaration {
             token,
@@ -278,11 +288,12 @@ impl Parser {
             parameters,
             body,
             return_type,
+            cleanup,
         })
     }
 
-    fn parse_function_parameters(&mut self) -> Vec<(String, String)> {
-        let mut parameters: Vec<(String, String)> = Vec::new();
+    fn parse_function_parameters(&mut self) -> Vec<(String, TypeAnnotation)> {
+        let mut parameters: Vec<(String, TypeAnnotation)> = Vec::new();
 
         if self.peek_token_is(TokenType::RightParen) {
             return parameters; // Empty parameter list
@@ -301,12 +312,10 @@ impl Parser {
                 return parameters;
             }
 
-            if !self.expect_peek(TokenType::Identifier(String::new())) {
-                return parameters;
-            }
-            let type_name = match self.current_token.token_type.clone() {
-                TokenType::Identifier(identifier) => identifier,
-                _ => unreachable!(),
+            self.next_token(); // consume ':'
+            let type_name = match self.parse_type_annotation() {
+                Some(type_name) => type_name,
+                None => return parameters,
             };
 
             parameters.push((name, type_name));
@@ -320,6 +329,40 @@ impl Parser {
         parameters
     }
 
+    // Parses a type starting at the current token, e.g. `int` or `int | float`
+    fn parse_type_annotation(&mut self) -> Option<TypeAnnotation> {
+        let mut names = vec![self.parse_type_name()?];
+
+        while self.peek_token_is(TokenType::Pipe) {
+            self.next_token(); // consume type
+            self.next_token(); // consume '|'
+            names.push(self.parse_type_name()?);
+        }
+
+        if names.len() == 1 {
+            names.pop().map(TypeAnnotation::Named)
+        } else {
+            Some(TypeAnnotation::Union(names))
+        }
+    }
+
+    fn parse_type_name(&mut self) -> Option<String> {
+        match self.current_token.token_type.clone() {
+            TokenType::IntKeyword => Some("int".to_string()),
+            TokenType::FloatKeyword => Some("float".to_string()),
+            TokenType::StringKeyword => Some("string".to_string()),
+            TokenType::BoolKeyword => Some("bool".to_string()),
+            TokenType::Identifier(identifier) => Some(identifier),
+            _ => {
+                self.error(format!(
+                    "Expected type name, got: {:?} instead",
+                    self.current_token.token_type
+                ));
+                None
+            }
+        }
+    }
+
     fn parse_return_statement(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
         let mut value = None;
@@ -367,6 +410,7 @@ impl Parser {
                 // Check if it's a dict literal or a block statement
                 if self.peek_token_is(TokenType::Identifier(String::new()))
                     || self.peek_token_is(TokenType::String(String::new()))
+                    || self.peek_token_is(TokenType::LeftBracket)
                 {
                     self.parse_dict_literal() // Call the new parsing function
                 } else {
@@ -380,9 +424,13 @@ impl Parser {
                 self.next_token();
                 Some(Expression::This { token })
             }
+            TokenType::SuperKeyword => self.parse_super_call_expression(),
+            TokenType::CloneKeyword => self.parse_clone_expression(),
+            TokenType::DeferKeyword => self.parse_defer_expression(),
+            TokenType::DeleteKeyword => self.parse_delete_expression(),
+            TokenType::FreezeKeyword => self.parse_freeze_expression(),
             _ => {
-                self.errors
-                    .push(format!("Unexpected token: {:?}", self.current_token));
+                self.error(format!("Unexpected token: {:?}", self.current_token));
                 return None;
             }
         };
@@ -430,6 +478,10 @@ impl Parser {
                     self.next_token();
                     left_expr = self.parse_assignment_expression(left_expr.unwrap());
                 }
+                TokenType::QuestionMark => {
+                    self.next_token();
+                    left_expr = self.parse_ternary_expression(left_expr.unwrap());
+                }
                 _ => return left_expr,
             }
 
@@ -466,6 +518,94 @@ impl Parser {
         })
     }
 
+    fn parse_super_call_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        // Only the superclass constructor can be called through `super`
+        if !self.expect_peek(TokenType::LeftParen) {
+            return None;
+        }
+
+        let arguments = self.parse_expression_list(TokenType::RightParen)?;
+
+        Some(Expression::SuperCall { token, arguments })
+    }
+
+    fn parse_clone_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenType::LeftParen) {
+            return None;
+        }
+
+        self.next_token(); // consume '('
+        let value = self.parse_expression(None)?;
+
+        if !self.expect_peek(TokenType::RightParen) {
+            return None;
+        }
+
+        Some(Expression::Clone {
+            token,
+            value: Box::new(value),
+        })
+    }
+
+    fn parse_freeze_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenType::LeftParen) {
+            return None;
+        }
+
+        self.next_token(); // consume '('
+        let value = self.parse_expression(None)?;
+
+        if !self.expect_peek(TokenType::RightParen) {
+            return None;
+        }
+
+        Some(Expression::Freeze {
+            token,
+            value: Box::new(value),
+        })
+    }
+
+    fn parse_defer_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        self.next_token(); // consume 'defer'
+        let expression = self.parse_expression(None)?;
+
+        Some(Expression::Defer {
+            token,
+            expression: Box::new(expression),
+        })
+    }
+
+    fn parse_delete_expression(&mut self) -> Option<Expression> {
+        let token = self.current_token.clone();
+        let precedence = self.prefix_precedence();
+
+        self.next_token(); // consume 'delete'
+        let target = self.parse_expression(Some(precedence))?;
+
+        // Only a property or element can be removed
+        if !matches!(target, Expression::MemberAccess { .. } | Expression::IndexAccess { .. }) {
+            self.errors.push(ParseError {
+                message: "Invalid delete target".to_string(),
+                line: token.line,
+                column: token.column,
+            });
+            return None;
+        }
+
+        Some(Expression::Delete {
+            token,
+            target: Box::new(target),
+        })
+    }
+
     fn parse_expression_list(&mut self, terminator: TokenType) -> Option<Vec<Expression>> {
         let mut expressions = Vec::new();
 
@@ -476,6 +616,7 @@ impl Parser {
         }
 
         loop {
+            self.next_token(); // consume '(' or ','
             // Parse the next expression, consuming the current token
             let expr = self.parse_expression(None);
             if let Some(expr) = expr {
@@ -494,6 +635,12 @@ impl Parser {
             }
 
             self.next_token(); // consume ','
+
+            // Allow a trailing comma, as dict literals do
+            if self.peek_token_is(terminator.clone()) {
+                self.next_token(); // consume the terminator
+                return Some(expressions);
+            }
         }
     }
 
@@ -516,8 +663,7 @@ impl Parser {
             TokenType::TrueKeyword => LiteralValue::Bool(true),
             TokenType::FalseKeyword => LiteralValue::Bool(false),
             _ => {
-                self.errors
-                    .push(format!("Unexpected token: {:?}", self.current_token));
+                self.error(format!("Unexpected token: {:?}", self.current_token));
                 return None;
             }
         };
@@ -531,8 +677,7 @@ impl Parser {
             TokenType::Minus => TokenType::Minus,
             TokenType::LogicalNot => TokenType::LogicalNot,
             _ => {
-                self.errors
-                    .push(format!("Unexpected token: {:?}", self.current_token));
+                self.error(format!("Unexpected token: {:?}", self.current_token));
                 return None;
             }
         };
@@ -564,8 +709,7 @@ impl Parser {
             | TokenType::LogicalAnd
             | TokenType::LogicalOr => token.token_type,
             _ => {
-                self.errors
-                    .push(format!("Unexpected token: {:?}", self.current_token));
+                self.error(format!("Unexpected token: {:?}", self.current_token));
                 return None;
             }
         };
@@ -592,12 +736,16 @@ impl Parser {
             TokenType::SlashEquals => TokenType::SlashEquals,
             TokenType::PercentEquals => TokenType::PercentEquals,
             _ => {
-                self.errors
-                    .push(format!("Unexpected token: {:?}", self.current_token));
+                self.error(format!("Unexpected token: {:?}", self.current_token));
                 return None;
             }
         };
 
+        if !Self::is_assignable(&left) {
+            self.error(format!("Invalid assignment target before {:?}", token.token_type));
+            return None;
+        }
+
         self.next_token();
         let right = self.parse_expression(Some(self.assignment_precedence()));
 
@@ -608,6 +756,38 @@ impl Parser {
         })
     }
 
+    fn is_assignable(expression: &Expression) -> bool {
+        matches!(
+            expression,
+            Expression::Identifier { .. }
+                | Expression::MemberAccess { .. }
+                | Expression::IndexAccess { .. }
+        )
+    }
+
+    fn parse_ternary_expression(&mut self, condition: Expression) -> Option<Expression> {
+        let token = self.current_token.clone();
+
+        self.next_token(); // consume '?'
+        let then_expression = self.parse_expression(None)?;
+
+        if !self.expect_peek(TokenType::Colon) {
+            return None;
+        }
+
+        // Parse just below ternary precedence so `a ? b : c ? d : e` nests to the right
+        self.next_token(); // consume ':'
+        let precedence = self.assignment_precedence();
+        let else_expression = self.parse_expression(Some(precedence))?;
+
+        Some(Expression::Ternary {
+            token,
+            condition: Box::new(condition),
+            then_expression: Box::new(then_expression),
+            else_expression: Box::new(else_expression),
+        })
+    }
+
     fn parse_call_expression(&mut self, function: Expression) -> Option<Expression> {
         let token = self.current_token.clone();
         let mut arguments = Vec::new();
@@ -622,12 +802,12 @@ impl Parser {
         }
 
         self.next_token(); // consume first argument
-        arguments.push(self.parse_expression(None).unwrap());
+        arguments.push(self.parse_call_argument()?);
 
         while self.peek_token_is(TokenType::Comma) {
             self.next_token(); // consume ','
             self.next_token(); // consume next argument
-            arguments.push(self.parse_expression(None).unwrap());
+            arguments.push(self.parse_call_argument()?);
         }
 
         if !self.expect_peek(TokenType::RightParen) {
@@ -641,6 +821,26 @@ impl Parser {
         })
     }
 
+    fn parse_call_argument(&mut self) -> Option<Expression> {
+        // Named argument: `name: value`
+        if let TokenType::Identifier(name) = self.current_token.token_type.clone() {
+            if self.peek_token_is(TokenType::Colon) {
+                let token = self.current_token.clone();
+                self.next_token(); // consume name
+                self.next_token(); // consume ':'
+                let value = self.parse_expression(None)?;
+
+                return Some(Expression::NamedArgument {
+                    token,
+                    name,
+                    value: Box::new(value),
+                });
+            }
+        }
+
+        self.parse_expression(None)
+    }
+
     fn parse_dict_literal(&mut self) -> Option<Expression> {
         let token = self.current_token.clone(); // '{' token
         let mut pairs = Vec::new();
@@ -648,50 +848,89 @@ impl Parser {
         self.next_token(); // consume '{'
 
         while !self.current_token_is(TokenType::RightBrace) {
-            let key = match self.parse_expression(None) {
-                Some(expr) => expr,
-                None => {
-                    // Error handling: Expected expression for key
-                    return None;
-                }
-            };
+            pairs.push(self.parse_object_property(true)?);
 
-            if !self.expect_peek(TokenType::Colon) {
-                // Error handling: Expected ':' after key
-                return None;
+            if !self.peek_token_is(TokenType::Comma) {
+                break; // No comma, end of dict literal
             }
+            self.next_token(); // consume ','
+            self.next_token(); // move to the next key, or to '}' after a trailing comma
+        }
 
-            self.next_token(); // consume ':'
+        // A trailing comma leaves us on '}' already; otherwise it must come next
+        if !self.current_token_is(TokenType::RightBrace) && !self.expect_peek(TokenType::RightBrace) {
+            // Error handling: Expected '}' to close the dict literal
+            return None;
+        }
 
-            let value = match self.parse_expression(None) {
-                Some(expr) => expr,
-                None => {
-                    // Error handling: Expected expression for value
-                    return None;
-                }
-            };
+        Some(Expression::DictLiteral { token, pairs })
+    }
 
-            pairs.push((key, value));
+    // Parses `key: value` or `[keyExpr]: value`, starting at the key.
+    // Dict literals also take any expression as a key, e.g. `{ 2: "two" }`
+    fn parse_object_property(&mut self, expression_keys: bool) -> Option<ObjectProperty> {
+        if self.current_token_is(TokenType::LeftBracket) {
+            self.next_token(); // consume '['
+            let key = self.parse_expression(None)?;
 
-            if self.peek_token_is(TokenType::Comma) {
-                self.next_token(); // consume ','
-            } else {
-                break; // No comma, end of dict literal
+            if !self.expect_peek(TokenType::RightBracket) {
+                return None;
             }
+
+            let value = self.parse_property_value()?;
+            return Some(ObjectProperty::Computed(key, value));
         }
 
-        if !self.expect_peek(TokenType::RightBrace) {
-            // Error handling: Expected '}' to close the dict literal
+        let key = match self.current_token.token_type.clone() {
+            TokenType::Identifier(key) | TokenType::String(key)
+                if !expression_keys || self.peek_token_is(TokenType::Colon) =>
+            {
+                key
+            }
+            _ if expression_keys => {
+                let key = self.parse_expression(None)?;
+                let value = self.parse_property_value()?;
+                return Some(ObjectProperty::Computed(key, value));
+            }
+            _ => {
+                self.error(format!(
+                    "Expected property key, got: {:?} instead",
+                    self.current_token.token_type
+                ));
+                return None;
+            }
+        };
+
+        let value = self.parse_property_value()?;
+        Some(ObjectProperty::Named(key, value))
+    }
+
+    // Parses `: value` after a property key
+    fn parse_property_value(&mut self) -> Option<Expression> {
+        if !self.expect_peek(TokenType::Colon) {
+            // Error handling: Expected ':' after key
             return None;
         }
 
-        Some(Expression::DictLiteral { token, pairs })
+        self.next_token(); // consume ':'
+        self.parse_expression(None)
     }
 
     fn parse_index_expression(&mut self, array: Expression) -> Option<Expression> {
         let token = self.current_token.clone();
+
+        // Slice without a start: `arr[:end]`, `arr[::step]`, `arr[:]`
+        if self.peek_token_is(TokenType::Colon) {
+            return self.parse_slice_expression(token, array, None);
+        }
+
         self.next_token(); // consume '['
         let index = self.parse_expression(None);
+
+        if self.peek_token_is(TokenType::Colon) {
+            return self.parse_slice_expression(token, array, Some(index?));
+        }
+
         if !self.expect_peek(TokenType::RightBracket) {
             return None;
         }
@@ -703,6 +942,45 @@ impl Parser {
         })
     }
 
+    fn parse_slice_expression(
+        &mut self,
+        token: Token,
+        array: Expression,
+        start: Option<Expression>,
+    ) -> Option<Expression> {
+        self.next_token(); // consume the token before the first ':'
+        let end = self.parse_slice_bound();
+
+        let step = if self.peek_token_is(TokenType::Colon) {
+            self.next_token(); // consume the token before the second ':'
+            self.parse_slice_bound()
+        } else {
+            None
+        };
+
+        if !self.expect_peek(TokenType::RightBracket) {
+            return None;
+        }
+
+        Some(Expression::SliceAccess {
+            token,
+            array: Box::new(array),
+            start: start.map(Box::new),
+            end,
+            step,
+        })
+    }
+
+    fn parse_slice_bound(&mut self) -> Option<Box<Expression>> {
+        // The current token is a ':'; an omitted bound is followed by ':' or ']'
+        if self.peek_token_is(TokenType::Colon) || self.peek_token_is(TokenType::RightBracket) {
+            return None;
+        }
+
+        self.next_token(); // consume ':'
+        self.parse_expression(None).map(Box::new)
+    }
+
     fn parse_member_access(&mut self, object: Expression) -> Option<Expression> {
         let token = self.current_token.clone();
         if !self.expect_peek(TokenType::Identifier(String::new())) {
@@ -745,9 +1023,12 @@ impl Parser {
 
             if self.peek_token_is(TokenType::IfKeyword) {
                 self.next_token(); // consume 'if'
-                else_branch = Some(Box::new(self.parse_if_statement().unwrap()));
+                else_branch = Some(Box::new(self.parse_if_statement()?));
             } else if self.expect_peek(TokenType::LeftBrace) {
-                else_branch = Some(Box::new(self.parse_block_statement()));
+                // Like the then-branch, parse_block_statement steps past the '{' itself
+                else_branch = Some(Box::new(Statement::BlockStatement(
+                    self.parse_block_statement(),
+                )));
             } else {
                 return None;
             }
@@ -873,27 +1154,79 @@ impl Parser {
         })
     }
 
-    fn parse_for_of_statement(&mut self) -> Option<Statement> {
+    // Looks past `for` for `key in` or `(key in`
+    fn is_for_in_loop(&self) -> bool {
+        let mut upcoming = std::iter::once(&self.peek_token)
+            .chain(self.lexer.tokens.iter())
+            .map(|token| &token.token_type)
+            .peekable();
+
+        if upcoming.peek() == Some(&&TokenType::LeftParen) {
+            upcoming.next();
+        }
+        matches!(upcoming.next(), Some(TokenType::Identifier(_))) && upcoming.next() == Some(&TokenType::InKeyword)
+    }
+
+    // Looks past 'for' for `[(] name [, name] of`
+    fn is_for_of_loop(&self) -> bool {
+        let mut upcoming = std::iter::once(&self.peek_token)
+            .chain(self.lexer.tokens.iter())
+            .map(|token| &token.token_type)
+            .peekable();
+
+        if upcoming.peek() == Some(&&TokenType::LeftParen) {
+            upcoming.next();
+        }
+
+        // Loop variables are names, type names, commas and `{ ... }` patterns
+        let mut seen_variable = false;
+        while let Some(token_type) = upcoming.next() {
+            match token_type {
+                TokenType::OfKeyword => return seen_variable,
+                TokenType::LeftBrace => {
+                    if !upcoming.any(|token_type| *token_type == TokenType::RightBrace) {
+                        return false;
+                    }
+                }
+                TokenType::Identifier(_)
+                | TokenType::IntKeyword
+                | TokenType::FloatKeyword
+                | TokenType::StringKeyword
+                | TokenType::BoolKeyword
+                | TokenType::Comma => {}
+                _ => return false,
+            }
+            seen_variable = true;
+        }
+        false
+    }
+
+    fn parse_for_in_statement(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
 
-        // Expect an identifier (element variable) after "for"
+        let has_parens = self.peek_token_is(TokenType::LeftParen);
+        if has_parens {
+            self.next_token(); // consume 'for'
+        }
+
+        // Expect the key variable after "for"
         if !self.expect_peek(TokenType::Identifier(String::new())) {
             return None;
         }
-        let element_variable = match self.current_token.token_type.clone() {
+        let key_variable = match self.current_token.token_type.clone() {
             TokenType::Identifier(identifier) => identifier,
             _ => unreachable!(),
         };
 
-        // Expect "of" keyword
-        if !self.expect_peek(TokenType::OfKeyword) {
+        if !self.expect_peek(TokenType::InKeyword) {
             return None;
         }
 
-        // Parse the iterable expression
-        self.next_token(); // consume 'of'
-        let iterator = self.parse_expression(None);
-        if iterator.is_none() {
+        // Parse the dict or object whose keys are visited
+        self.next_token(); // consume 'in'
+        let iterator = self.parse_expression(None)?;
+
+        if has_parens && !self.expect_peek(TokenType::RightParen) {
             return None;
         }
 
@@ -903,23 +1236,152 @@ impl Parser {
         }
         let body = self.parse_block_statement();
 
-        Some(Statement::ForEachStatement {
+        Some(Statement::ForInStatement {
             token,
-            element_variable,
-            iterator: iterator.unwrap(),
+            key_variable,
+            iterator,
             body: Box::new(Statement::BlockStatement(body)),
         })
     }
 
-    fn parse_break_statement(&mut self) -> Option<Statement> {
-        let token = self.current_token.clone();
-        Some(Statement::BreakStatement { token })
-    }
+    // Parses `name`, `type name` or `{ type name, ... }` starting at the current token
+    fn parse_loop_variable(&mut self) -> Option<LoopVariable> {
+        if self.current_token_is(TokenType::LeftBrace) {
+            let mut properties = Vec::new();
+            loop {
+                self.next_token(); // consume '{' or ','
+                let type_name = self.parse_type_name()?;
+                if !self.expect_peek(TokenType::Identifier(String::new())) {
+                    return None;
+                }
+                let name = match self.current_token.token_type.clone() {
+                    TokenType::Identifier(identifier) => identifier,
+                    _ => unreachable!(),
+                };
+                properties.push((name, type_name));
 
-    fn parse_continue_statement(&mut self) -> Option<Statement> {
-        let token = self.current_token.clone();
-        Some(Statement::ContinueStatement { token })
-    }
+                if !self.peek_token_is(TokenType::Comma) {
+                    break;
+                }
+                self.next_token(); // consume property name
+            }
+
+            if !self.expect_peek(TokenType::RightBrace) {
+                return None;
+            }
+            return Some(LoopVariable::Object(properties));
+        }
+
+        // A second name means the first one was the element's type
+        if matches!(self.peek_token.token_type, TokenType::Identifier(_)) {
+            let type_name = self.parse_type_name()?;
+            self.next_token(); // consume type name
+            let name = match self.current_token.token_type.clone() {
+                TokenType::Identifier(identifier) => identifier,
+                _ => unreachable!(),
+            };
+            return Some(LoopVariable::Simple(name, Some(type_name)));
+        }
+
+        match self.current_token.token_type.clone() {
+            TokenType::Identifier(identifier) => Some(LoopVariable::Simple(identifier, None)),
+            _ => {
+                self.error(format!(
+                    "Expected loop variable, got: {:?} instead",
+                    self.current_token.token_type
+                ));
+                None
+            }
+        }
+    }
+
+    fn parse_for_of_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        let has_parens = self.peek_token_is(TokenType::LeftParen);
+        if has_parens {
+            self.next_token(); // consume 'for'
+        }
+
+        self.next_token(); // consume 'for' or '('
+
+        // for (index, element of array)
+        let mut index_variable = None;
+        if let TokenType::Identifier(identifier) = self.current_token.token_type.clone() {
+            if self.peek_token_is(TokenType::Comma) {
+                index_variable = Some(identifier);
+                self.next_token(); // consume index variable
+                self.next_token(); // consume ','
+            }
+        }
+
+        let element_variable = self.parse_loop_variable()?;
+
+        // Expect "of" keyword
+        if !self.expect_peek(TokenType::OfKeyword) {
+            return None;
+        }
+
+        // Parse the iterable expression
+        self.next_token(); // consume 'of'
+        let iterator = self.parse_expression(None);
+        if iterator.is_none() {
+            return None;
+        }
+
+        if has_parens && !self.expect_peek(TokenType::RightParen) {
+            return None;
+        }
+
+        // Expect a block statement for the loop body
+        if !self.expect_peek(TokenType::LeftBrace) {
+            return None;
+        }
+        let body = self.parse_block_statement();
+
+        Some(Statement::ForEachStatement {
+            token,
+            index_variable,
+            element_variable,
+            iterator: iterator.unwrap(),
+            body: Box::new(Statement::BlockStatement(body)),
+        })
+    }
+
+    fn parse_break_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+        if !self.expect_peek(TokenType::Semicolon) {
+            return None;
+        }
+        Some(Statement::BreakStatement { token })
+    }
+
+    fn parse_continue_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+        if !self.expect_peek(TokenType::Semicolon) {
+            return None;
+        }
+        Some(Statement::ContinueStatement { token })
+    }
+
+    // Parses the statements after a case or default label, stopping before the next label
+    fn parse_switch_case_body(&mut self) -> Vec<Statement> {
+        let mut statements = Vec::new();
+        while !self.peek_token_is(TokenType::CaseKeyword)
+            && !self.peek_token_is(TokenType::DefaultKeyword)
+            && !self.peek_token_is(TokenType::RightBrace)
+            && !self.peek_token_is(TokenType::EOF)
+        {
+            self.next_token(); // consume ':' or the end of the previous statement
+            if self.current_token_is(TokenType::LeftBrace) {
+                // A braced body such as `case 1: { ... }` is a nested block
+                statements.push(Statement::BlockStatement(self.parse_block_statement()));
+            } else if let Some(stmt) = self.parse_statement() {
+                statements.push(stmt);
+            }
+        }
+        statements
+    }
 
     fn parse_switch_statement(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
@@ -941,28 +1403,26 @@ impl Parser {
         let mut cases = Vec::new();
         let mut default = None;
 
-        while !self.peek_token_is(TokenType::RightBrace) {
+        while !self.peek_token_is(TokenType::RightBrace) && !self.peek_token_is(TokenType::EOF) {
             match self.peek_token.token_type {
                 TokenType::CaseKeyword => {
                     self.next_token(); // consume 'case'
                     self.next_token(); // consume expression
-                    let case_expr = self.parse_expression(None).unwrap();
+                    let mut case_values = vec![self.parse_expression(None)?];
+
+                    // Several values can share one body: case 1, 2, 3:
+                    while self.peek_token_is(TokenType::Comma) {
+                        self.next_token(); // consume ','
+                        self.next_token(); // consume next expression
+                        case_values.push(self.parse_expression(None)?);
+                    }
 
                     if !self.expect_peek(TokenType::Colon) {
                         return None;
                     }
 
-                    let mut statements = Vec::new();
-                    while !self.peek_token_is(TokenType::CaseKeyword)
-                        && !self.peek_token_is(TokenType::DefaultKeyword)
-                        && !self.peek_token_is(TokenType::RightBrace)
-                    {
-                        if let Some(stmt) = self.parse_statement() {
-                            statements.push(stmt);
-                        }
-                        self.next_token();
-                    }
-                    cases.push((case_expr, statements));
+                    let statements = self.parse_switch_case_body();
+                    cases.push((case_values, statements));
                 }
                 TokenType::DefaultKeyword => {
                     self.next_token(); // consume 'default'
@@ -970,20 +1430,11 @@ impl Parser {
                         return None;
                     }
 
-                    let mut statements = Vec::new();
-                    while !self.peek_token_is(TokenType::CaseKeyword)
-                        && !self.peek_token_is(TokenType::DefaultKeyword)
-                        && !self.peek_token_is(TokenType::RightBrace)
-                    {
-                        if let Some(stmt) = self.parse_statement() {
-                            statements.push(stmt);
-                        }
-                        self.next_token();
-                    }
+                    let statements = self.parse_switch_case_body();
                     default = Some(statements);
                 }
                 _ => {
-                    self.errors.push(format!(
+                    self.error_at_peek(format!(
                         "Unexpected token in switch statement: {:?}",
                         self.peek_token.token_type
                     ));
@@ -1004,6 +1455,27 @@ impl Parser {
         })
     }
 
+    fn parse_cancel_statement(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+
+        if !self.expect_peek(TokenType::LeftParen) {
+            return None;
+        }
+
+        self.next_token(); // consume '('
+        let handle = self.parse_expression(None)?;
+
+        if !self.expect_peek(TokenType::RightParen) {
+            return None;
+        }
+
+        if !self.expect_peek(TokenType::Semicolon) {
+            return None;
+        }
+
+        Some(Statement::CancelStatement { token, handle })
+    }
+
     fn parse_enum_declaration(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
         if !self.expect_peek(TokenType::Identifier(String::new())) {
@@ -1043,46 +1515,45 @@ impl Parser {
 
     fn parse_object_declaration(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
-        let name = if self.peek_token_is(TokenType::Identifier(String::new())) {
-            self.next_token();
-            match self.current_token.token_type.clone() {
-                TokenType::Identifier(identifier) => Some(identifier),
-                _ => unreachable!(),
-            }
-        } else {
-            None
+        if !self.peek_token_is(TokenType::Identifier(String::new())) {
+            self.error_at_peek("Expected identifier after 'object'".to_string());
+            return None;
+        }
+        self.next_token(); // consume 'object'
+        let name = match self.current_token.token_type.clone() {
+            TokenType::Identifier(identifier) => identifier,
+            _ => unreachable!(),
         };
 
         if !self.expect_peek(TokenType::Equals) {
             return None;
         }
-        self.next_token(); // consume '='
 
-        if !self.expect_peek(TokenType::LeftBrace) {
-            return None;
-        }
+        if !self.peek_token_is(TokenType::LeftBrace) {
+            // Not a literal, e.g. `object merged = objA + objB;`
+            self.next_token(); // consume '='
+            let value = self.parse_expression(None);
 
-        let mut properties = Vec::new();
-        while !self.peek_token_is(TokenType::RightBrace) {
-            if !self.expect_peek(TokenType::Identifier(String::new())) {
+            if !self.expect_peek(TokenType::Semicolon) {
                 return None;
             }
-            let key = match self.current_token.token_type.clone() {
-                TokenType::Identifier(identifier) => identifier,
-                _ => unreachable!(),
-            };
 
-            if !self.expect_peek(TokenType::Colon) {
-                return None;
-            }
+            return Some(Statement::VariableDeclaration {
+                token,
+                name,
+                type_name: Some(TypeAnnotation::Named("object".to_string())),
+                value,
+            });
+        }
 
-            self.next_token(); // consume ':'
-            let value = self.parse_expression(None);
-            if value.is_none() {
-                return None;
-            }
+        if !self.expect_peek(TokenType::LeftBrace) {
+            return None;
+        }
 
-            properties.push((key, value.unwrap()));
+        let mut properties = Vec::new();
+        while !self.peek_token_is(TokenType::RightBrace) {
+            self.next_token(); // move to the key
+            properties.push(self.parse_object_property(false)?);
 
             if !self.peek_token_is(TokenType::Comma) {
                 break;
@@ -1100,11 +1571,89 @@ impl Parser {
 
         Some(Statement::ObjectDeclaration {
             token,
-            name: name.unwrap_or_else(|| "".to_string()),
+            name,
             properties,
         })
     }
 
+    fn parse_union_declaration(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+        if !self.expect_peek(TokenType::Identifier(String::new())) {
+            return None;
+        }
+
+        let name = match self.current_token.token_type.clone() {
+            TokenType::Identifier(identifier) => identifier,
+            _ => unreachable!(),
+        };
+
+        if !self.expect_peek(TokenType::LeftBrace) {
+            return None;
+        }
+
+        self.next_token(); // consume '{'
+        let members = match self.parse_type_annotation()? {
+            TypeAnnotation::Named(name) => vec![name],
+            TypeAnnotation::Union(names) => names,
+        };
+
+        if !self.expect_peek(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(Statement::UnionDeclaration {
+            token,
+            name,
+            members,
+        })
+    }
+
+    fn parse_struct_declaration(&mut self) -> Option<Statement> {
+        let token = self.current_token.clone();
+        if !self.expect_peek(TokenType::Identifier(String::new())) {
+            return None;
+        }
+
+        let name = match self.current_token.token_type.clone() {
+            TokenType::Identifier(identifier) => identifier,
+            _ => unreachable!(),
+        };
+
+        if !self.expect_peek(TokenType::LeftBrace) {
+            return None;
+        }
+
+        let mut fields = Vec::new();
+        while !self.peek_token_is(TokenType::RightBrace) {
+            self.next_token(); // consume type
+            let type_name = self.parse_type_name()?;
+
+            if !self.expect_peek(TokenType::Identifier(String::new())) {
+                return None;
+            }
+            let field_name = match self.current_token.token_type.clone() {
+                TokenType::Identifier(identifier) => identifier,
+                _ => unreachable!(),
+            };
+
+            if !self.expect_peek(TokenType::Semicolon) {
+                return None;
+            }
+
+            fields.push((field_name, type_name));
+        }
+
+        if !self.expect_peek(TokenType::RightBrace) {
+            return None;
+        }
+
+        Some(Statement::StructDeclaration {
+            token,
+            name,
+            fields,
+        })
+    }
+
     fn parse_class_declaration(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
         if !self.expect_peek(TokenType::Identifier(String::new())) {
@@ -1129,6 +1678,27 @@ impl Parser {
             None
         };
 
+        // class C extends A with B, D
+        let mut mixins = Vec::new();
+        if self.peek_token_is(TokenType::WithKeyword) {
+            self.next_token(); // consume 'with'
+
+            loop {
+                if !self.expect_peek(TokenType::Identifier(String::new())) {
+                    return None;
+                }
+                mixins.push(match self.current_token.token_type.clone() {
+                    TokenType::Identifier(identifier) => identifier,
+                    _ => unreachable!(),
+                });
+
+                if !self.peek_token_is(TokenType::Comma) {
+                    break;
+                }
+                self.next_token(); // consume ','
+            }
+        }
+
         let mut interfaces = Vec::new();
         if self.peek_token_is(TokenType::ImplementsKeyword) {
             self.next_token(); // consume 'implements'
@@ -1163,6 +1733,7 @@ impl Parser {
             token,
             name,
             superclass,
+            mixins,
             interfaces,
             members,
         })
@@ -1171,16 +1742,19 @@ impl Parser {
     fn parse_class_members(&mut self) -> Vec<ClassMember> {
         let mut members = Vec::new();
 
-        while !self.peek_token_is(TokenType::RightBrace) {
+        while !self.peek_token_is(TokenType::RightBrace) && !self.peek_token_is(TokenType::EOF) {
             let member = self.parse_class_member();
             if let Some(member) = member {
                 members.push(member);
             } else {
-                // Skip to the next semicolon or closing brace to recover
-                while !self.current_token_is(TokenType::Semicolon)
-                    && !self.current_token_is(TokenType::RightBrace)
-                {
+                // Skip past the next semicolon or closing brace to recover, always advancing
+                while !self.peek_token_is(TokenType::RightBrace) && !self.peek_token_is(TokenType::EOF) {
                     self.next_token();
+                    if self.current_token_is(TokenType::Semicolon)
+                        || self.current_token_is(TokenType::RightBrace)
+                    {
+                        break;
+                    }
                 }
             }
         }
@@ -1197,8 +1771,27 @@ impl Parser {
             false
         };
 
+        // Static initializer block: static { ... }
+        if is_static && self.peek_token_is(TokenType::LeftBrace) {
+            let token = self.current_token.clone();
+            self.next_token(); // consume 'static'
+            let body = self.parse_block_statement();
+            return Some(ClassMember::StaticInitializer { token, body });
+        }
+
+        let is_override = if self.peek_token_is(TokenType::OverridesKeyword) {
+            self.next_token(); // consume 'overrides'
+            if !self.peek_token_is(TokenType::FunctionKeyword) {
+                self.peek_error(TokenType::FunctionKeyword);
+                return None;
+            }
+            true
+        } else {
+            false
+        };
+
         if self.peek_token_is(TokenType::FunctionKeyword) {
-            self.parse_method_declaration(visibility, is_static)
+            self.parse_method_declaration(visibility, is_static, is_override)
         } else {
             self.parse_field_declaration(visibility, is_static)
         }
@@ -1223,12 +1816,21 @@ impl Parser {
     ) -> Option<ClassMember> {
         let token = self.current_token.clone();
 
-        let type_name = if let TokenType::Identifier(_) = self.peek_token.token_type {
-            self.next_token(); // consume type
-            Some(match self.current_token.token_type.clone() {
-                TokenType::Identifier(identifier) => identifier,
-                _ => unreachable!(),
-            })
+        // `int count` and `Point origin` are typed, a lone `count` is not
+        let has_type = match self.peek_token.token_type {
+            TokenType::IntKeyword
+            | TokenType::FloatKeyword
+            | TokenType::StringKeyword
+            | TokenType::BoolKeyword => true,
+            TokenType::Identifier(_) => matches!(
+                self.lexer.tokens.first().map(|token| &token.token_type),
+                Some(TokenType::Identifier(_))
+            ),
+            _ => false,
+        };
+        let type_name = if has_type {
+            self.next_token(); // consume the token before the type
+            Some(self.parse_type_name()?)
         } else {
             None
         };
@@ -1244,6 +1846,7 @@ impl Parser {
 
         let mut value = None;
         if self.peek_token_is(TokenType::Equals) {
+            self.next_token(); // consume name
             self.next_token(); // consume '='
             value = self.parse_expression(None);
         }
@@ -1266,6 +1869,7 @@ impl Parser {
         &mut self,
         visibility: Visibility,
         is_static: bool,
+        is_override: bool,
     ) -> Option<ClassMember> {
         let token = self.current_token.clone();
         self.next_token(); // consume 'function'
@@ -1290,28 +1894,31 @@ impl Parser {
         }
 
         let return_type = if self.peek_token_is(TokenType::EqualsGreaterThan) {
+            self.next_token(); // consume ')'
             self.next_token(); // consume '=>'
-            if !self.expect_peek(TokenType::Identifier(String::new())) {
-                return None;
-            }
-            Some(match self.current_token.token_type.clone() {
-                TokenType::Identifier(identifier) => identifier,
-                _ => unreachable!(),
-            })
+            Some(self.parse_type_annotation()?)
         } else {
             None
         };
 
+        // A ';' instead of a body declares an abstract method
+        if self.peek_token_is(TokenType::Semicolon) {
+            self.next_token(); // consume ';'
+            return Some(ClassMember::AbstractMethod {
+                token,
+                name,
+                parameters,
+                return_type,
+            });
+        }
+
         if !self.expect_peek(TokenType::LeftBrace) {
             return None;
         }
 
+        // parse_block_statement stops on the closing '}'
         let body = self.parse_block_statement();
 
-        if !self.expect_peek(TokenType::RightBrace) {
-            return None;
-        }
-
         Some(ClassMember::Method {
             token,
             name,
@@ -1320,6 +1927,7 @@ impl Parser {
             return_type,
             visibility,
             is_static,
+            is_override,
         })
     }
 
@@ -1354,16 +1962,19 @@ impl Parser {
     fn parse_interface_members(&mut self) -> Vec<InterfaceMember> {
         let mut members = Vec::new();
 
-        while !self.peek_token_is(TokenType::RightBrace) {
+        while !self.peek_token_is(TokenType::RightBrace) && !self.peek_token_is(TokenType::EOF) {
             let member = self.parse_interface_member();
             if let Some(member) = member {
                 members.push(member);
             } else {
-                // Skip to the next semicolon or closing brace to recover
-                while !self.current_token_is(TokenType::Semicolon)
-                    && !self.current_token_is(TokenType::RightBrace)
-                {
+                // Skip past the next semicolon or closing brace to recover, always advancing
+                while !self.peek_token_is(TokenType::RightBrace) && !self.peek_token_is(TokenType::EOF) {
                     self.next_token();
+                    if self.current_token_is(TokenType::Semicolon)
+                        || self.current_token_is(TokenType::RightBrace)
+                    {
+                        break;
+                    }
                 }
             }
         }
@@ -1372,7 +1983,12 @@ impl Parser {
     }
 
     fn parse_interface_member(&mut self) -> Option<InterfaceMember> {
-        self.parse_method_declaration(Visibility::Public, false) // Interface methods are always public and non-static
+        if self.peek_token_is(TokenType::ConstKeyword) {
+            self.next_token(); // consume 'const'
+            return self.parse_interface_constant();
+        }
+
+        self.parse_method_declaration(Visibility::Public, false, false) // Interface methods are always public, non-static and never override
             .map(|member| match member {
                 ClassMember::Method {
                     token,
@@ -1386,10 +2002,41 @@ impl Parser {
                     parameters,
                     return_type,
                 },
+                ClassMember::AbstractMethod {
+                    token,
+                    name,
+                    parameters,
+                    return_type,
+                } => InterfaceMember::Method {
+                    token,
+                    name,
+                    parameters,
+                    return_type,
+                },
                 _ => unreachable!(),
             })
     }
 
+    fn parse_interface_constant(&mut self) -> Option<InterfaceMember> {
+        match self.parse_const_variable_declaration()? {
+            Statement::VariableDeclaration {
+                token,
+                name,
+                type_name: Some(type_name),
+                value: Some(value),
+            } => Some(InterfaceMember::Constant {
+                token,
+                name,
+                type_name,
+                value,
+            }),
+            _ => {
+                self.error("Expected constant declaration".to_string());
+                None
+            }
+        }
+    }
+
     fn parse_import_declaration(&mut self) -> Option<Statement> {
         let token = self.current_token.clone();
         let mut imports = Vec::new();
@@ -1421,12 +2068,26 @@ impl Parser {
             }
         } else if self.expect_peek(TokenType::Identifier(String::new())) {
             // Default import: import identifier from 'module-name';
-            let identifier = match self.current_token.token_type.clone() {
+            let name = match self.current_token.token_type.clone() {
                 TokenType::Identifier(identifier) => identifier,
                 _ => unreachable!(),
             };
 
-            imports.push(ImportSpecifier::Default(identifier));
+            // Optional rename: import identifier as alias from 'module-name';
+            let alias = if self.peek_token_is(TokenType::AsKeyword) {
+                self.next_token(); // consume 'as'
+                if !self.expect_peek(TokenType::Identifier(String::new())) {
+                    return None;
+                }
+                Some(match self.current_token.token_type.clone() {
+                    TokenType::Identifier(identifier) => identifier,
+                    _ => unreachable!(),
+                })
+            } else {
+                None
+            };
+
+            imports.push(ImportSpecifier::Default { name, alias });
         } else {
             self.peek_error(TokenType::Identifier(String::new()));
             return None;
@@ -1460,9 +2121,17 @@ impl Parser {
         let mut specifiers = Vec::new();
 
         if self.peek_token_is(TokenType::DefaultKeyword) {
-            // export default expression;
+            // export default expression; or export default declaration
+            self.next_token(); // consume 'export'
             self.next_token(); // consume 'default'
-            specifiers.push(ExportSpecifier::Default);
+            let statement = self.parse_statement()?;
+            specifiers.push(ExportSpecifier::Default(Box::new(statement)));
+
+            // The exported statement already consumed its own terminator
+            return Some(Statement::ExportDeclaration {
+                token,
+                specifiers,
+            });
         } else if self.peek_token_is(TokenType::LeftBrace) {
             // Named exports: export { identifier1, identifier2 };
             self.next_token(); // consume '{'
@@ -1532,25 +2201,26 @@ impl Parser {
             | TokenType::StarEquals
             | TokenType::SlashEquals
             | TokenType::PercentEquals => 1,
-            TokenType::LogicalOr => 2,
-            TokenType::LogicalAnd => 3,
-            TokenType::EqualsEquals | TokenType::NotEquals => 4,
+            TokenType::QuestionMark => 2,
+            TokenType::LogicalOr => 3,
+            TokenType::LogicalAnd => 4,
+            TokenType::EqualsEquals | TokenType::NotEquals => 5,
             TokenType::GreaterThan
             | TokenType::LessThan
             | TokenType::GreaterThanEquals
-            | TokenType::LessThanEquals => 5,
-            TokenType::Plus | TokenType::Minus => 6,
-            TokenType::Star | TokenType::Slash | TokenType::Percent => 7,
-            TokenType::LeftParen => 8,
-            TokenType::LeftBracket => 9,
-            TokenType::Dot => 10,
+            | TokenType::LessThanEquals => 6,
+            TokenType::Plus | TokenType::Minus => 7,
+            TokenType::Star | TokenType::Slash | TokenType::Percent => 8,
+            TokenType::LeftParen => 9,
+            TokenType::LeftBracket => 10,
+            TokenType::Dot => 11,
             _ => -1,
         }
     }
 
     fn prefix_precedence(&mut self) -> i32 {
         match self.current_token.token_type {
-            TokenType::Minus | TokenType::LogicalNot => 7,
+            TokenType::Minus | TokenType::LogicalNot | TokenType::DeleteKeyword => 8,
             _ => -1,
         }
     }
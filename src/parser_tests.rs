@@ -1,6 +1,7 @@
 use crate::lexer::Lexer;
 use crate::parser::Parser;
 use crate::ast::*;
+use crate::error::ParseError;
 use crate::token::TokenType;
 
 #[test]
@@ -33,9 +34,10 @@ fn test_parse_switch_statement() {
         } => {
             assert!(matches!(**expression, Expression::Identifier { .. }));
             assert_eq!(cases.len(), 2);
-            assert!(matches!(cases[0].0, Expression::Literal { .. }));
+            assert_eq!(cases[0].0.len(), 1);
+            assert!(matches!(cases[0].0[0], Expression::Literal { .. }));
             assert_eq!(cases[0].1.len(), 2);
-            assert!(matches!(cases[1].0, Expression::Literal { .. }));
+            assert!(matches!(cases[1].0[0], Expression::Literal { .. }));
             assert_eq!(cases[1].1.len(), 2);
             assert_eq!(default.as_ref().unwrap().len(), 1);
         }
@@ -64,7 +66,7 @@ fn test_parse_for_of_statement() {
             body,
             ..
         } => {
-            assert_eq!(element_variable, "element");
+            assert_eq!(element_variable, &LoopVariable::Simple("element".to_string(), None));
             assert!(matches!(**iterator, Expression::Identifier { .. }));
             assert_eq!(body.len(), 1);
         }
@@ -230,6 +232,56 @@ fn test_parse_enum_declaration() {
     }
 }
 
+#[test]
+fn test_parse_identifier_placeholder_matches_any_name() {
+    let input = " int count = 5;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::VariableDeclaration { name, .. } => assert_eq!(name, "count"),
+        _ => panic!("Expected VariableDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_string_placeholder_matches_any_string() {
+    let input = r#" { "first": 1 };"#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::Expression(Expression::DictLiteral { pairs, .. }) => {
+            assert!(matches!(&pairs[0], ObjectProperty::Named(key, _) if key == "first"));
+        }
+        _ => panic!("Expected Expression statement with DictLiteral"),
+    }
+}
+
+#[test]
+fn test_parse_payload_tokens_only_match_their_own_variant() {
+    // An int literal carries a payload too, but is not an identifier
+    let input = " int 5 = 3;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    assert!(parser
+        .get_errors()
+        .iter()
+        .any(|error| error.message.starts_with("Expected token: Identifier")));
+}
+
 #[test]
 fn test_parse_object_declaration() {
     let input = r#"
@@ -406,4 +458,1355 @@ fn test_invalid_syntax_error_handling() {
 
     assert_eq!(program.len(), 1);
     assert!(!parser.get_errors().is_empty());
+}
+
+#[test]
+fn test_parse_clone_expression() {
+    let input = r#"
+        object copy = clone(original);
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::VariableDeclaration {
+            name,
+            value: Some(Expression::Clone { value, .. }),
+            ..
+        } => {
+            assert_eq!(name, "copy");
+            assert!(matches!(**value, Expression::Identifier { .. }));
+        }
+        _ => panic!("Expected VariableDeclaration with Clone"),
+    }
+}
+
+#[test]
+fn test_parse_clone_expression_missing_paren() {
+    let input = r#"
+        clone original;
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    assert!(!parser.get_errors().is_empty());
+}
+
+#[test]
+fn test_parse_interface_constants() {
+    let input = r#"
+        interface Buffer {
+            const int MAX_SIZE = 100;
+            const string NAME = "buffer";
+
+            function size() => int {}
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::InterfaceDeclaration {
+            name,
+            members,
+            ..
+        } => {
+            assert_eq!(name, "Buffer");
+            assert_eq!(members.len(), 3);
+            match &members[0] {
+                InterfaceMember::Constant {
+                    name,
+                    type_name,
+                    value: Expression::Literal { value: LiteralValue::Int(value), .. },
+                    ..
+                } => {
+                    assert_eq!(name, "MAX_SIZE");
+                    assert_eq!(type_name, &TypeAnnotation::Named("int".to_string()));
+                    assert_eq!(*value, 100);
+                }
+                _ => panic!("Expected int Constant"),
+            }
+            match &members[1] {
+                InterfaceMember::Constant {
+                    name,
+                    type_name,
+                    value: Expression::Literal { value: LiteralValue::String(value), .. },
+                    ..
+                } => {
+                    assert_eq!(name, "NAME");
+                    assert_eq!(type_name, &TypeAnnotation::Named("string".to_string()));
+                    assert_eq!(value, "buffer");
+                }
+                _ => panic!("Expected string Constant"),
+            }
+            assert!(matches!(members[2], InterfaceMember::Method { .. }));
+        }
+        _ => panic!("Expected InterfaceDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_dict_literal_expression_statement() {
+    let input = r#"
+        { first: 1, second: 2 };
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::Expression(Expression::DictLiteral { pairs, .. }) => {
+            assert_eq!(pairs.len(), 2);
+            assert!(matches!(&pairs[0], ObjectProperty::Named(key, _) if key == "first"));
+            assert!(matches!(
+                pairs[1],
+                ObjectProperty::Named(_, Expression::Literal { value: LiteralValue::Int(2), .. })
+            ));
+        }
+        _ => panic!("Expected Expression statement with DictLiteral"),
+    }
+}
+
+#[test]
+fn test_parse_dict_literal_trailing_comma() {
+    let input = r#"
+        { first: 1, second: 2, };
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::Expression(Expression::DictLiteral { pairs, .. }) => {
+            assert_eq!(pairs.len(), 2);
+        }
+        _ => panic!("Expected Expression statement with DictLiteral"),
+    }
+}
+
+#[test]
+fn test_parse_dict_literal_expression_keys() {
+    let input = r#"
+        { a: 1, 2: 3, a + b: 4 };
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::Expression(Expression::DictLiteral { pairs, .. }) => {
+            assert_eq!(pairs.len(), 3);
+            assert!(matches!(&pairs[0], ObjectProperty::Named(key, _) if key == "a"));
+            assert!(matches!(
+                pairs[1],
+                ObjectProperty::Computed(Expression::Literal { value: LiteralValue::Int(2), .. }, _)
+            ));
+            assert!(matches!(
+                pairs[2],
+                ObjectProperty::Computed(Expression::BinaryOperation { .. }, _)
+            ));
+        }
+        _ => panic!("Expected Expression statement with DictLiteral"),
+    }
+}
+
+#[test]
+fn test_parse_object_merge() {
+    let input = r#"
+        object merged = objA + objB;
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::VariableDeclaration {
+            name,
+            type_name,
+            value: Some(Expression::BinaryOperation { left, operator, right, .. }),
+            ..
+        } => {
+            assert_eq!(name, "merged");
+            assert_eq!(type_name, &Some(TypeAnnotation::Named("object".to_string())));
+            assert_eq!(*operator, TokenType::Plus);
+            assert!(matches!(**left, Expression::Identifier { .. }));
+            assert!(matches!(**right, Expression::Identifier { .. }));
+        }
+        _ => panic!("Expected VariableDeclaration with BinaryOperation"),
+    }
+}
+
+#[test]
+fn test_parse_object_without_name_is_error() {
+    let input = " object = objA + objB;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    assert!(parser
+        .get_errors()
+        .iter()
+        .any(|error| error.message == "Expected identifier after 'object'"));
+}
+
+#[test]
+fn test_parse_error_display() {
+    let error = ParseError {
+        message: "Expected identifier after 'object'".to_string(),
+        line: 2,
+        column: 8,
+    };
+    assert_eq!(
+        format!("{}", error),
+        "error at 2:8: Expected identifier after 'object'"
+    );
+}
+
+#[test]
+fn test_parse_error_position() {
+    let input = "\n object = objA;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let equals = lexer.tokens.iter().find(|token| token.token_type == TokenType::Equals).unwrap();
+    let (line, column) = (equals.line, equals.column);
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    // Points at the '=' where the name should have been
+    let error = &parser.get_errors()[0];
+    assert_eq!((error.line, error.column), (line, column));
+    assert_eq!(error.line, 2);
+}
+
+#[test]
+fn test_parse_function_finally_block() {
+    let input = r#"
+        function process(path: string) => int {
+            if (path == "") {
+                return 0;
+            }
+            return 1;
+        } finally {
+            close(path);
+        }
+
+        function plain() {
+            return;
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::FunctionDeclaration { name, body, cleanup, .. } => {
+            assert_eq!(name, "process");
+            assert_eq!(body.len(), 2);
+            assert_eq!(cleanup.as_ref().unwrap().len(), 1);
+        }
+        _ => panic!("Expected FunctionDeclaration"),
+    }
+    match &program[1] {
+        Statement::FunctionDeclaration { name, cleanup, .. } => {
+            assert_eq!(name, "plain");
+            assert!(cleanup.is_none());
+        }
+        _ => panic!("Expected FunctionDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_abstract_method() {
+    let input = r#"
+        class Shape {
+            public function area() => float;
+
+            public function describe() => string {
+                return "shape";
+            }
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ClassDeclaration { members, .. } => {
+            assert_eq!(members.len(), 2);
+            match &members[0] {
+                ClassMember::AbstractMethod { name, return_type, .. } => {
+                    assert_eq!(name, "area");
+                    assert_eq!(return_type, &Some(TypeAnnotation::Named("float".to_string())));
+                }
+                _ => panic!("Expected AbstractMethod"),
+            }
+            match &members[1] {
+                ClassMember::Method { name, body, .. } => {
+                    assert_eq!(name, "describe");
+                    assert_eq!(body.len(), 1);
+                }
+                _ => panic!("Expected Method"),
+            }
+        }
+        _ => panic!("Expected ClassDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_default_import_alias() {
+    let input = r#"
+        import math as m from "math";
+        import strings from "strings";
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::ImportDeclaration { imports, path, .. } => {
+            assert_eq!(path, "math");
+            match &imports[0] {
+                ImportSpecifier::Default { name, alias } => {
+                    assert_eq!(name, "math");
+                    assert_eq!(alias.as_deref(), Some("m"));
+                }
+                _ => panic!("Expected default import"),
+            }
+        }
+        _ => panic!("Expected ImportDeclaration"),
+    }
+    match &program[1] {
+        Statement::ImportDeclaration { imports, path, .. } => {
+            assert_eq!(path, "strings");
+            match &imports[0] {
+                ImportSpecifier::Default { name, alias } => {
+                    assert_eq!(name, "strings");
+                    assert!(alias.is_none());
+                }
+                _ => panic!("Expected default import"),
+            }
+        }
+        _ => panic!("Expected ImportDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_named_arguments() {
+    let input = r#"
+        move(x: 1, y: 2);
+        move(1, 2);
+        move(1, y: 2);
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 3);
+
+    let arguments = |statement: &Statement| -> Vec<Option<String>> {
+        match statement {
+            Statement::Expression(Expression::FunctionCall { arguments, .. }) => arguments
+                .iter()
+                .map(|argument| match argument {
+                    Expression::NamedArgument { name, .. } => Some(name.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => panic!("Expected FunctionCall"),
+        }
+    };
+
+    assert_eq!(
+        arguments(&program[0]),
+        vec![Some("x".to_string()), Some("y".to_string())]
+    );
+    assert_eq!(arguments(&program[1]), vec![None, None]);
+    assert_eq!(arguments(&program[2]), vec![None, Some("y".to_string())]);
+}
+
+#[test]
+fn test_parse_slice_access() {
+    let input = r#"
+        arr[1:3];
+        arr[:2];
+        arr[::2];
+        arr[1::-1];
+        arr[:];
+        arr[1];
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 6);
+
+    // (has start, has end, has step) for each slice
+    let bounds = |statement: &Statement| match statement {
+        Statement::Expression(Expression::SliceAccess { start, end, step, .. }) => {
+            (start.is_some(), end.is_some(), step.is_some())
+        }
+        _ => panic!("Expected SliceAccess"),
+    };
+
+    assert_eq!(bounds(&program[0]), (true, true, false));
+    assert_eq!(bounds(&program[1]), (false, true, false));
+    assert_eq!(bounds(&program[2]), (false, false, true));
+    assert_eq!(bounds(&program[3]), (true, false, true));
+    assert_eq!(bounds(&program[4]), (false, false, false));
+    assert!(matches!(
+        program[5],
+        Statement::Expression(Expression::IndexAccess { .. })
+    ));
+}
+
+#[test]
+fn test_parse_static_initializer() {
+    let input = r#"
+        class Config {
+            static int count;
+
+            static {
+                count = 10;
+                load();
+            }
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ClassDeclaration { members, .. } => {
+            assert_eq!(members.len(), 2);
+            assert!(matches!(members[0], ClassMember::Field { is_static: true, .. }));
+            match &members[1] {
+                ClassMember::StaticInitializer { body, .. } => {
+                    assert_eq!(body.len(), 2);
+                }
+                _ => panic!("Expected StaticInitializer"),
+            }
+        }
+        _ => panic!("Expected ClassDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_overrides_method() {
+    let input = r#"
+        class Circle extends Shape {
+            public overrides function area() => float {
+                return 3.14;
+            }
+
+            public function radius() => float {
+                return 1.0;
+            }
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ClassDeclaration { members, .. } => {
+            assert_eq!(members.len(), 2);
+            assert!(matches!(
+                members[0],
+                ClassMember::Method { is_override: true, .. }
+            ));
+            assert!(matches!(
+                members[1],
+                ClassMember::Method { is_override: false, .. }
+            ));
+        }
+        _ => panic!("Expected ClassDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_switch_case_group() {
+    let input = r#"
+        switch(x) {
+            case 1, 2, 3:
+                print("low");
+                break;
+            case 4:
+                print("four");
+                break;
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::SwitchStatement { cases, default, .. } => {
+            assert_eq!(cases.len(), 2);
+            assert_eq!(cases[0].0.len(), 3);
+            for (expected, value) in [1, 2, 3].iter().zip(&cases[0].0) {
+                match value {
+                    Expression::Literal { value: LiteralValue::Int(value), .. } => {
+                        assert_eq!(value, expected)
+                    }
+                    _ => panic!("Expected int Literal"),
+                }
+            }
+            assert_eq!(cases[0].1.len(), 2);
+            assert_eq!(cases[1].0.len(), 1);
+            assert!(default.is_none());
+        }
+        _ => panic!("Expected SwitchStatement"),
+    }
+}
+
+#[test]
+fn test_parse_break_and_continue() {
+    let input = r#"
+        while (running) {
+            if (skip) {
+                continue;
+            }
+            break;
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::WhileStatement { body, .. } => match &**body {
+            Statement::BlockStatement(statements) => {
+                assert_eq!(statements.len(), 2);
+                assert!(matches!(statements[1], Statement::BreakStatement { .. }));
+            }
+            _ => panic!("Expected BlockStatement"),
+        },
+        _ => panic!("Expected WhileStatement"),
+    }
+}
+
+#[test]
+fn test_parse_switch_case_block_body() {
+    let input = r#"
+        switch(x) {
+            case 1: {
+                print("one");
+                break;
+            }
+            case 2:
+                print("two");
+            default: { print("other"); }
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::SwitchStatement { cases, default, .. } => {
+            assert_eq!(cases.len(), 2);
+            match &cases[0].1[..] {
+                [Statement::BlockStatement(statements)] => assert_eq!(statements.len(), 2),
+                _ => panic!("Expected a single BlockStatement"),
+            }
+            assert_eq!(cases[1].1.len(), 1);
+            assert!(matches!(default.as_deref(), Some([Statement::BlockStatement(_)])));
+        }
+        _ => panic!("Expected SwitchStatement"),
+    }
+}
+
+#[test]
+fn test_parse_defer_and_cancel() {
+    let input = r#"
+        int deferHandle = defer cleanup();
+        cancel(deferHandle);
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::VariableDeclaration {
+            name,
+            value: Some(Expression::Defer { expression, .. }),
+            ..
+        } => {
+            assert_eq!(name, "deferHandle");
+            assert!(matches!(**expression, Expression::FunctionCall { .. }));
+        }
+        _ => panic!("Expected VariableDeclaration with Defer"),
+    }
+    match &program[1] {
+        Statement::CancelStatement { handle, .. } => {
+            assert!(matches!(handle, Expression::Identifier { .. }));
+        }
+        _ => panic!("Expected CancelStatement"),
+    }
+}
+
+#[test]
+fn test_parse_struct_declaration() {
+    let input = r#"
+        struct Point {
+            int x;
+            int y;
+            Color color;
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::StructDeclaration { name, fields, .. } => {
+            assert_eq!(name, "Point");
+            assert_eq!(
+                fields,
+                &vec![
+                    ("x".to_string(), "int".to_string()),
+                    ("y".to_string(), "int".to_string()),
+                    ("color".to_string(), "Color".to_string()),
+                ]
+            );
+        }
+        _ => panic!("Expected StructDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_struct_declaration_missing_semicolon() {
+    let input = r#"
+        struct Point {
+            int x
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    assert!(!parser.get_errors().is_empty());
+}
+
+#[test]
+fn test_parse_union_types() {
+    let input = r#"
+        union Number { int | float }
+
+        int | float total = 0;
+
+        function half(value: int | float) => int | float {
+            return value / 2;
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    let number = || TypeAnnotation::Union(vec!["int".to_string(), "float".to_string()]);
+
+    assert_eq!(program.len(), 3);
+    match &program[0] {
+        Statement::UnionDeclaration { name, members, .. } => {
+            assert_eq!(name, "Number");
+            assert_eq!(members, &vec!["int".to_string(), "float".to_string()]);
+        }
+        _ => panic!("Expected UnionDeclaration"),
+    }
+    match &program[1] {
+        Statement::VariableDeclaration { name, type_name, .. } => {
+            assert_eq!(name, "total");
+            assert_eq!(type_name, &Some(number()));
+        }
+        _ => panic!("Expected VariableDeclaration"),
+    }
+    match &program[2] {
+        Statement::FunctionDeclaration { parameters, return_type, .. } => {
+            assert_eq!(parameters.len(), 1);
+            assert_eq!(parameters[0].0, "value");
+            assert_eq!(parameters[0].1, number());
+            assert_eq!(return_type, &Some(number()));
+        }
+        _ => panic!("Expected FunctionDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_string_slice_and_index() {
+    let input = r#"
+        "hello"[1:3];
+        name[0];
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::Expression(Expression::SliceAccess { array, start, end, step, .. }) => {
+            assert!(matches!(
+                **array,
+                Expression::Literal { value: LiteralValue::String(_), .. }
+            ));
+            assert!(start.is_some() && end.is_some() && step.is_none());
+        }
+        _ => panic!("Expected SliceAccess"),
+    }
+    assert!(matches!(
+        program[1],
+        Statement::Expression(Expression::IndexAccess { .. })
+    ));
+}
+
+#[test]
+fn test_parse_computed_property_keys() {
+    let input = r#"
+        object Lookup = {
+            ["prefix" + name]: 1,
+            [index + 1]: 2,
+            [key]: 3,
+            plain: 4
+        };
+        { [key]: value };
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::ObjectDeclaration { properties, .. } => {
+            assert_eq!(properties.len(), 4);
+            assert!(matches!(
+                properties[0],
+                ObjectProperty::Computed(Expression::BinaryOperation { .. }, _)
+            ));
+            assert!(matches!(
+                properties[1],
+                ObjectProperty::Computed(Expression::BinaryOperation { .. }, _)
+            ));
+            assert!(matches!(
+                properties[2],
+                ObjectProperty::Computed(Expression::Identifier { .. }, _)
+            ));
+            assert!(matches!(&properties[3], ObjectProperty::Named(key, _) if key == "plain"));
+        }
+        _ => panic!("Expected ObjectDeclaration"),
+    }
+    match &program[1] {
+        Statement::Expression(Expression::DictLiteral { pairs, .. }) => {
+            assert_eq!(pairs.len(), 1);
+            assert!(matches!(
+                pairs[0],
+                ObjectProperty::Computed(Expression::Identifier { .. }, Expression::Identifier { .. })
+            ));
+        }
+        _ => panic!("Expected DictLiteral"),
+    }
+}
+
+#[test]
+fn test_parse_if_else_statement() {
+    let input = r#"
+        if (x) { a; } else { b; }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::IfStatement {
+            then_branch,
+            else_branch: Some(else_branch),
+            ..
+        } => {
+            match &**then_branch {
+                Statement::BlockStatement(statements) => assert_eq!(statements.len(), 1),
+                _ => panic!("Expected BlockStatement"),
+            }
+            match &**else_branch {
+                Statement::BlockStatement(statements) => assert_eq!(statements.len(), 1),
+                _ => panic!("Expected BlockStatement"),
+            }
+        }
+        _ => panic!("Expected IfStatement with else branch"),
+    }
+}
+
+#[test]
+fn test_parse_if_else_if_chain() {
+    let input = r#"
+        if (x == 1) {
+            a;
+        } else if (x == 2) {
+            b;
+        } else {
+            c;
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::IfStatement {
+            else_branch: Some(else_branch),
+            ..
+        } => match &**else_branch {
+            Statement::IfStatement {
+                then_branch,
+                else_branch: Some(else_branch),
+                ..
+            } => {
+                assert!(matches!(&**then_branch, Statement::BlockStatement(s) if s.len() == 1));
+                assert!(matches!(&**else_branch, Statement::BlockStatement(s) if s.len() == 1));
+            }
+            _ => panic!("Expected nested IfStatement"),
+        },
+        _ => panic!("Expected IfStatement with else branch"),
+    }
+}
+
+#[test]
+fn test_parse_ternary_expression() {
+    let input = r#"
+        int max = a > b ? a : b;
+        int sign = x < 0 ? -1 : x == 0 ? 0 : 1;
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::VariableDeclaration {
+            value: Some(Expression::Ternary { condition, then_expression, else_expression, .. }),
+            ..
+        } => {
+            assert!(matches!(**condition, Expression::BinaryOperation { .. }));
+            assert!(matches!(**then_expression, Expression::Identifier { .. }));
+            assert!(matches!(**else_expression, Expression::Identifier { .. }));
+        }
+        _ => panic!("Expected VariableDeclaration with Ternary"),
+    }
+    match &program[1] {
+        Statement::VariableDeclaration {
+            value: Some(Expression::Ternary { then_expression, else_expression, .. }),
+            ..
+        } => {
+            assert!(matches!(**then_expression, Expression::UnaryOperation { .. }));
+            assert!(matches!(**else_expression, Expression::Ternary { .. }));
+        }
+        _ => panic!("Expected VariableDeclaration with nested Ternary"),
+    }
+}
+
+#[test]
+fn test_parse_for_of_with_index() {
+    let input = r#"
+        for (idx, elem of arr) {
+            print(idx);
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ForEachStatement { index_variable, element_variable, .. } => {
+            assert_eq!(index_variable.as_deref(), Some("idx"));
+            assert_eq!(element_variable, &LoopVariable::Simple("elem".to_string(), None));
+        }
+        _ => panic!("Expected ForEachStatement"),
+    }
+}
+
+#[test]
+fn test_parse_for_of_without_index() {
+    let input = r#"
+        for elem of arr {
+            print(elem);
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ForEachStatement { index_variable, element_variable, .. } => {
+            assert!(index_variable.is_none());
+            assert_eq!(element_variable, &LoopVariable::Simple("elem".to_string(), None));
+        }
+        _ => panic!("Expected ForEachStatement"),
+    }
+}
+
+#[test]
+fn test_parse_class_with_mixin() {
+    let input = r#"
+        class Dog extends Animal with Walker {
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ClassDeclaration { superclass, mixins, .. } => {
+            assert_eq!(superclass.as_deref(), Some("Animal"));
+            assert_eq!(mixins, &vec!["Walker".to_string()]);
+        }
+        _ => panic!("Expected ClassDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_class_with_multiple_mixins() {
+    let input = r#"
+        class Duck extends Animal with Walker, Swimmer implements Noisy {
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ClassDeclaration { mixins, interfaces, .. } => {
+            assert_eq!(mixins, &vec!["Walker".to_string(), "Swimmer".to_string()]);
+            assert_eq!(interfaces, &vec!["Noisy".to_string()]);
+        }
+        _ => panic!("Expected ClassDeclaration"),
+    }
+}
+
+fn assert_invalid_assignment_target(input: &str) {
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    assert!(parser
+        .get_errors()
+        .iter()
+        .any(|error| error.message.starts_with("Invalid assignment target")));
+}
+
+#[test]
+fn test_parse_assignment_to_literal_is_error() {
+    assert_invalid_assignment_target(" 5 = 10;");
+    assert_invalid_assignment_target(" \"name\" = 10;");
+}
+
+#[test]
+fn test_parse_assignment_to_call_is_error() {
+    assert_invalid_assignment_target(" foo() = 10;");
+}
+
+#[test]
+fn test_parse_assignment_to_binary_operation_is_error() {
+    assert_invalid_assignment_target(" a + b = 10;");
+}
+
+#[test]
+fn test_parse_compound_assignment_to_literal_is_error() {
+    assert_invalid_assignment_target(" 5 += 1;");
+}
+
+#[test]
+fn test_parse_assignment_to_index_access() {
+    let input = " items[0] = 10;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::Expression(Expression::Assignment { left, .. }) => {
+            assert!(matches!(**left, Expression::IndexAccess { .. }));
+        }
+        _ => panic!("Expected assignment expression"),
+    }
+}
+
+#[test]
+fn test_parse_delete_expression() {
+    let input = r#"
+        delete config.debug;
+        delete scores["bob"];
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::Expression(Expression::Delete { target, .. }) => {
+            assert!(matches!(**target, Expression::MemberAccess { .. }));
+        }
+        _ => panic!("Expected delete expression"),
+    }
+    match &program[1] {
+        Statement::Expression(Expression::Delete { target, .. }) => {
+            assert!(matches!(**target, Expression::IndexAccess { .. }));
+        }
+        _ => panic!("Expected delete expression"),
+    }
+}
+
+#[test]
+fn test_parse_delete_invalid_target() {
+    for input in [" delete config;", " delete 5;"] {
+        let mut lexer = Lexer::new(input);
+        lexer.tokenize();
+        let mut parser = Parser::new(lexer);
+        parser.parse_program();
+
+        assert!(parser
+            .get_errors()
+            .iter()
+            .any(|error| error.message.starts_with("Invalid delete target")));
+    }
+}
+
+#[test]
+fn test_parse_delete_binds_tighter_than_assignment() {
+    // Parses as (delete a.b) = 5, not delete (a.b = 5)
+    let input = " delete a.b = 5;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    let errors = parser.get_errors();
+    assert!(errors.iter().any(|error| error.message.starts_with("Invalid assignment target")));
+    assert!(!errors.iter().any(|error| error.message.starts_with("Invalid delete target")));
+}
+
+#[test]
+fn test_parse_freeze_expression() {
+    let input = " object config = freeze(defaults);";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::VariableDeclaration { value: Some(Expression::Freeze { value, .. }), .. } => {
+            assert!(matches!(**value, Expression::Identifier { .. }));
+        }
+        _ => panic!("Expected freeze expression"),
+    }
+}
+
+#[test]
+fn test_parse_for_of_with_destructuring() {
+    let input = r#"
+        for ({ int x, int y } of points) {
+            print(x + y);
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::ForEachStatement { index_variable, element_variable, .. } => {
+            assert_eq!(index_variable, &None);
+            assert_eq!(
+                element_variable,
+                &LoopVariable::Object(vec![
+                    ("x".to_string(), "int".to_string()),
+                    ("y".to_string(), "int".to_string()),
+                ])
+            );
+        }
+        _ => panic!("Expected ForEachStatement"),
+    }
+}
+
+#[test]
+fn test_parse_for_of_with_typed_variable() {
+    let input = r#"
+        for (i, Point p of points) {
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    match &program[0] {
+        Statement::ForEachStatement { index_variable, element_variable, .. } => {
+            assert_eq!(index_variable.as_deref(), Some("i"));
+            assert_eq!(element_variable, &LoopVariable::Simple("p".to_string(), Some("Point".to_string())));
+        }
+        _ => panic!("Expected ForEachStatement"),
+    }
+}
+
+fn parse_default_export(input: &str) -> Statement {
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let mut program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    assert_eq!(program.len(), 1);
+    match program.pop() {
+        Some(Statement::ExportDeclaration { mut specifiers, .. }) => match specifiers.pop() {
+            Some(ExportSpecifier::Default(statement)) => *statement,
+            _ => panic!("Expected default export specifier"),
+        },
+        _ => panic!("Expected ExportDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_export_default_expression() {
+    let statement = parse_default_export(" export default 0;");
+    assert!(matches!(
+        statement,
+        Statement::Expression(Expression::Literal { value: LiteralValue::Int(0), .. })
+    ));
+}
+
+#[test]
+fn test_parse_export_default_function() {
+    let statement = parse_default_export(" export default function main() { return 0; }");
+    match statement {
+        Statement::FunctionDeclaration { name, .. } => assert_eq!(name, "main"),
+        _ => panic!("Expected FunctionDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_export_default_class() {
+    let statement = parse_default_export(" export default class App { }");
+    match statement {
+        Statement::ClassDeclaration { name, .. } => assert_eq!(name, "App"),
+        _ => panic!("Expected ClassDeclaration"),
+    }
+}
+
+#[test]
+fn test_parse_super_call() {
+    let input = r#"
+        super();
+        super(name, age + 1);
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    assert_eq!(program.len(), 2);
+    match &program[0] {
+        Statement::Expression(Expression::SuperCall { arguments, .. }) => assert!(arguments.is_empty()),
+        _ => panic!("Expected super call"),
+    }
+    match &program[1] {
+        Statement::Expression(Expression::SuperCall { arguments, .. }) => assert_eq!(arguments.len(), 2),
+        _ => panic!("Expected super call"),
+    }
+}
+
+#[test]
+fn test_parse_argument_lists() {
+    let cases = [
+        (" new Point();", 0),
+        (" new Point(x);", 1),
+        (" new Point(x, y + 1);", 2),
+        (" new Point(x, y,);", 2),
+        (" super(name,);", 1),
+    ];
+
+    for (input, expected) in cases {
+        let mut lexer = Lexer::new(input);
+        lexer.tokenize();
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program();
+
+        assert!(parser.get_errors().is_empty(), "{}: {:?}", input, parser.get_errors());
+        assert_eq!(program.len(), 1);
+        match &program[0] {
+            Statement::Expression(Expression::NewExpression { arguments, .. })
+            | Statement::Expression(Expression::SuperCall { arguments, .. }) => {
+                assert_eq!(arguments.len(), expected, "{}", input)
+            }
+            _ => panic!("Expected new expression or super call"),
+        }
+    }
+}
+
+#[test]
+fn test_parse_invalid_argument_lists() {
+    for input in [" new Point(x y);", " super(,);"] {
+        let mut lexer = Lexer::new(input);
+        lexer.tokenize();
+        let mut parser = Parser::new(lexer);
+        parser.parse_program();
+
+        assert!(!parser.get_errors().is_empty(), "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_super_without_call_is_error() {
+    let input = " super.name;";
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    parser.parse_program();
+
+    assert!(!parser.get_errors().is_empty());
+}
+
+#[test]
+fn test_parse_for_in() {
+    let input = r#"
+        for (key in myDict) {
+            print(key);
+        }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.get_errors().is_empty());
+    assert_eq!(program.len(), 1);
+    match &program[0] {
+        Statement::ForInStatement { key_variable, iterator, .. } => {
+            assert_eq!(key_variable, "key");
+            assert!(matches!(iterator, Expression::Identifier { name, .. } if name == "myDict"));
+        }
+        _ => panic!("Expected ForInStatement"),
+    }
+}
+
+#[test]
+fn test_parse_for_in_and_for_of_are_distinct() {
+    let input = r#"
+        for (key in myDict) { }
+        for (value of myArray) { }
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    lexer.tokenize();
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert_eq!(program.len(), 2);
+    assert!(matches!(program[0], Statement::ForInStatement { .. }));
+    assert!(matches!(program[1], Statement::ForEachStatement { .. }));
 }
\ No newline at end of file
@@ -0,0 +1,311 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use crate::ast::{ClassMember, ExportSpecifier, Expression, InterfaceMember, Statement};
+
+#[derive(Debug, PartialEq)]
+pub enum SemanticError {
+    MissingInterfaceMethod { class: String, interface: String, method: String },
+    MixinMethodConflict { class: String, method: String, sources: Vec<String> },
+    NothingToOverride { class: String, method: String },
+    NonExhaustiveSwitch { enum_name: String, missing_variants: Vec<String> },
+}
+
+impl fmt::Display for SemanticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SemanticError::MissingInterfaceMethod { class, interface, method } => write!(
+                f,
+                "Class '{}' does not implement method '{}' from interface '{}'",
+                class, method, interface
+            ),
+            SemanticError::MixinMethodConflict { class, method, sources } => write!(
+                f,
+                "Method '{}' of class '{}' is defined by both {} without an override",
+                method,
+                class,
+                sources.iter().map(|source| format!("'{}'", source)).collect::<Vec<_>>().join(" and ")
+            ),
+            SemanticError::NothingToOverride { class, method } => write!(
+                f,
+                "Method '{}' of class '{}' is marked 'overrides' but does not override an inherited method",
+                method, class
+            ),
+            SemanticError::NonExhaustiveSwitch { enum_name, missing_variants } => write!(
+                f,
+                "Switch over enum '{}' has no default and does not handle {}",
+                enum_name,
+                missing_variants.join(", ")
+            ),
+        }
+    }
+}
+
+struct ClassInfo<'a> {
+    superclass: Option<&'a str>,
+    mixins: &'a [String],
+    methods: HashSet<&'a str>,
+    overrides: HashSet<&'a str>,
+}
+
+// Checks a parsed program for errors the parser cannot see, such as a
+// class that is missing a method its interfaces require, or a switch that
+// skips some variants of an enum.
+pub struct Analyzer {
+    errors: Vec<SemanticError>,
+}
+
+impl Analyzer {
+    pub fn new() -> Self {
+        Analyzer { errors: Vec::new() }
+    }
+
+    pub fn get_errors(&self) -> &Vec<SemanticError> {
+        &self.errors
+    }
+
+    pub fn analyze(&mut self, program: &[Statement]) {
+        let mut interfaces = HashMap::new();
+        let mut classes = HashMap::new();
+        let mut enums = HashMap::new();
+        for statement in Self::declarations(program) {
+            match statement {
+                Statement::InterfaceDeclaration { name, members, .. } => {
+                    let methods: Vec<&str> = members
+                        .iter()
+                        .filter_map(|member| match member {
+                            InterfaceMember::Method { name, .. } => Some(name.as_str()),
+                            InterfaceMember::Constant { .. } => None,
+                        })
+                        .collect();
+                    interfaces.insert(name.as_str(), methods);
+                }
+                Statement::ClassDeclaration { name, superclass, mixins, members, .. } => {
+                    let methods = members
+                        .iter()
+                        .filter_map(|member| match member {
+                            ClassMember::Method { name, is_static: false, .. } | ClassMember::AbstractMethod { name, .. } => {
+                                Some(name.as_str())
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    let overrides = members
+                        .iter()
+                        .filter_map(|member| match member {
+                            ClassMember::Method { name, is_static: false, is_override: true, .. } => Some(name.as_str()),
+                            _ => None,
+                        })
+                        .collect();
+                    classes.insert(
+                        name.as_str(),
+                        ClassInfo { superclass: superclass.as_deref(), mixins, methods, overrides },
+                    );
+                }
+                Statement::EnumDeclaration { name, variants, .. } => {
+                    enums.insert(name.as_str(), variants.as_slice());
+                }
+                _ => {}
+            }
+        }
+
+        for statement in Self::declarations(program) {
+            if let Statement::ClassDeclaration { name, interfaces: implemented, .. } = statement {
+                self.check_mixin_conflicts(name, &classes);
+                self.check_overrides(name, &classes);
+
+                // An imported superclass or mixin may provide anything, so only check fully known chains
+                let Some(methods) = Self::inherited_methods(name, &classes) else {
+                    continue;
+                };
+                for interface in implemented {
+                    // Interfaces from other modules cannot be checked here
+                    let Some(required) = interfaces.get(interface.as_str()) else {
+                        continue;
+                    };
+                    for method in required.iter().filter(|method| !methods.contains(*method)) {
+                        self.errors.push(SemanticError::MissingInterfaceMethod {
+                            class: name.clone(),
+                            interface: interface.clone(),
+                            method: method.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        self.check_switches(program, &enums);
+    }
+
+    // A method the class gets from more than one place, counting the class's
+    // own declaration and each of its mixins, must be redeclared with
+    // 'overrides' to say which one wins
+    fn check_mixin_conflicts(&mut self, class: &str, classes: &HashMap<&str, ClassInfo>) {
+        let info = &classes[class];
+        let mut sources: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for method in info.methods.difference(&info.overrides) {
+            sources.entry(*method).or_default().push(class);
+        }
+        for mixin in info.mixins {
+            let Some(mixin_info) = classes.get(mixin.as_str()) else {
+                continue;
+            };
+            for method in mixin_info.methods.difference(&info.overrides) {
+                sources.entry(*method).or_default().push(mixin);
+            }
+        }
+
+        for (method, sources) in sources.into_iter().filter(|(_, sources)| sources.len() > 1) {
+            self.errors.push(SemanticError::MixinMethodConflict {
+                class: class.to_string(),
+                method: method.to_string(),
+                sources: sources.into_iter().map(str::to_string).collect(),
+            });
+        }
+    }
+
+    // Every 'overrides' method must replace one from a superclass or mixin
+    fn check_overrides(&mut self, class: &str, classes: &HashMap<&str, ClassInfo>) {
+        let info = &classes[class];
+        let mut inherited = HashSet::new();
+        if let Some(superclass) = info.superclass {
+            match Self::inherited_methods(superclass, classes) {
+                Some(methods) => inherited.extend(methods),
+                None => return,
+            }
+        }
+        for mixin in info.mixins {
+            match classes.get(mixin.as_str()) {
+                Some(mixin_info) => inherited.extend(mixin_info.methods.iter().copied()),
+                None => return,
+            }
+        }
+
+        let mut unmatched: Vec<&str> = info.overrides.difference(&inherited).copied().collect();
+        unmatched.sort_unstable();
+        for method in unmatched {
+            self.errors.push(SemanticError::NothingToOverride { class: class.to_string(), method: method.to_string() });
+        }
+    }
+
+    // Visits every switch in these statements and the bodies nested inside them
+    fn check_switches(&mut self, statements: &[Statement], enums: &HashMap<&str, &[String]>) {
+        for statement in statements {
+            match statement {
+                Statement::SwitchStatement { cases, default, .. } => {
+                    if default.is_none() {
+                        self.check_switch_exhaustive(cases, enums);
+                    }
+                    for (_, body) in cases {
+                        self.check_switches(body, enums);
+                    }
+                    if let Some(default) = default {
+                        self.check_switches(default, enums);
+                    }
+                }
+                Statement::FunctionDeclaration { body, cleanup, .. } => {
+                    self.check_switches(body, enums);
+                    if let Some(cleanup) = cleanup {
+                        self.check_switches(cleanup, enums);
+                    }
+                }
+                Statement::IfStatement { then_branch, else_branch, .. } => {
+                    self.check_switches(std::slice::from_ref(&**then_branch), enums);
+                    if let Some(else_branch) = else_branch {
+                        self.check_switches(std::slice::from_ref(&**else_branch), enums);
+                    }
+                }
+                Statement::DoWhileStatement { body, .. }
+                | Statement::WhileStatement { body, .. }
+                | Statement::ForStatement { body, .. }
+                | Statement::ForEachStatement { body, .. }
+                | Statement::ForInStatement { body, .. } => self.check_switches(std::slice::from_ref(&**body), enums),
+                Statement::ClassDeclaration { members, .. } => {
+                    for member in members {
+                        if let ClassMember::Method { body, .. } | ClassMember::StaticInitializer { body, .. } = member {
+                            self.check_switches(body, enums);
+                        }
+                    }
+                }
+                Statement::ExportDeclaration { specifiers, .. } => {
+                    for specifier in specifiers {
+                        if let ExportSpecifier::Default(statement) = specifier {
+                            self.check_switches(std::slice::from_ref(&**statement), enums);
+                        }
+                    }
+                }
+                Statement::BlockStatement(statements) => self.check_switches(statements, enums),
+                _ => {}
+            }
+        }
+    }
+
+    // Without types, a switch counts as being over an enum when its case
+    // labels name that enum's variants, as in `case Color.Red:`
+    fn check_switch_exhaustive(
+        &mut self,
+        cases: &[(Vec<Expression>, Vec<Statement>)],
+        enums: &HashMap<&str, &[String]>,
+    ) {
+        let labels: Vec<(&str, &str)> = cases
+            .iter()
+            .flat_map(|(values, _)| values)
+            .filter_map(|value| match value {
+                Expression::MemberAccess { object, member, .. } => match &**object {
+                    Expression::Identifier { name, .. } if enums.contains_key(name.as_str()) => {
+                        Some((name.as_str(), member.as_str()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        let Some(&(enum_name, _)) = labels.first() else {
+            return;
+        };
+
+        let missing_variants: Vec<String> = enums[enum_name]
+            .iter()
+            .filter(|variant| !labels.contains(&(enum_name, variant.as_str())))
+            .cloned()
+            .collect();
+        if !missing_variants.is_empty() {
+            self.errors.push(SemanticError::NonExhaustiveSwitch { enum_name: enum_name.to_string(), missing_variants });
+        }
+    }
+
+    // Top-level statements, looking through `export default` to the declaration it wraps
+    fn declarations(program: &[Statement]) -> impl Iterator<Item = &Statement> {
+        program.iter().flat_map(|statement| match statement {
+            Statement::ExportDeclaration { specifiers, .. } => specifiers
+                .iter()
+                .filter_map(|specifier| match specifier {
+                    ExportSpecifier::Default(statement) => Some(&**statement),
+                    ExportSpecifier::Named(_) => None,
+                })
+                .collect(),
+            _ => vec![statement],
+        })
+    }
+
+    // All instance methods of `class`, its superclasses and the mixins any of
+    // them use, or None when the chain leaves this program or loops back on
+    // itself. A mixin contributes the methods it declares itself.
+    fn inherited_methods<'a>(class: &'a str, classes: &HashMap<&'a str, ClassInfo<'a>>) -> Option<HashSet<&'a str>> {
+        let mut methods = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut current = Some(class);
+        while let Some(name) = current {
+            if !visited.insert(name) {
+                return None;
+            }
+            let info = classes.get(name)?;
+            methods.extend(info.methods.iter().copied());
+            for mixin in info.mixins {
+                methods.extend(classes.get(mixin.as_str())?.methods.iter().copied());
+            }
+            current = info.superclass;
+        }
+        Some(methods)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,299 @@
+use crate::ast::{ClassMember, ExportSpecifier, Expression, InterfaceMember, Statement, Visibility};
+use crate::semantic::{Analyzer, SemanticError};
+use crate::test_ast::*;
+use crate::token::TokenType;
+
+fn analyze(program: &[Statement]) -> Analyzer {
+    let mut analyzer = Analyzer::new();
+    analyzer.analyze(program);
+    analyzer
+}
+
+fn interface(name: &str, methods: &[&str]) -> Statement {
+    Statement::InterfaceDeclaration {
+        token: token(TokenType::InterfaceKeyword),
+        name: name.to_string(),
+        members: methods
+            .iter()
+            .map(|method| InterfaceMember::Method {
+                token: token(TokenType::Identifier(method.to_string())),
+                name: method.to_string(),
+                parameters: vec![],
+                return_type: None,
+            })
+            .collect(),
+    }
+}
+
+fn class(name: &str, superclass: Option<&str>, interfaces: &[&str], methods: &[&str]) -> Statement {
+    Statement::ClassDeclaration {
+        token: token(TokenType::ClassKeyword),
+        name: name.to_string(),
+        superclass: superclass.map(str::to_string),
+        mixins: vec![],
+        interfaces: interfaces.iter().map(|interface| interface.to_string()).collect(),
+        members: methods
+            .iter()
+            .map(|method| ClassMember::Method {
+                token: token(TokenType::Identifier(method.to_string())),
+                name: method.to_string(),
+                parameters: vec![],
+                body: vec![],
+                return_type: None,
+                visibility: Visibility::Public,
+                is_static: false,
+                is_override: false,
+            })
+            .collect(),
+    }
+}
+
+fn with_mixins(mut class: Statement, names: &[&str]) -> Statement {
+    if let Statement::ClassDeclaration { mixins, .. } = &mut class {
+        *mixins = names.iter().map(|name| name.to_string()).collect();
+    }
+    class
+}
+
+// Marks the named methods of a class built with `class` as 'overrides'
+fn with_overrides(mut class: Statement, names: &[&str]) -> Statement {
+    if let Statement::ClassDeclaration { members, .. } = &mut class {
+        for member in members {
+            if let ClassMember::Method { name, is_override, .. } = member {
+                *is_override = names.contains(&name.as_str());
+            }
+        }
+    }
+    class
+}
+
+fn enum_declaration(name: &str, variants: &[&str]) -> Statement {
+    Statement::EnumDeclaration {
+        token: token(TokenType::EnumKeyword),
+        name: name.to_string(),
+        variants: variants.iter().map(|variant| variant.to_string()).collect(),
+    }
+}
+
+// `switch (color) { case Color.A, Color.B: ... }`, one case per label group
+fn switch_on_color(labels: &[&[&str]], has_default: bool) -> Statement {
+    let variant = |name: &str| Expression::MemberAccess {
+        token: token(TokenType::Dot),
+        object: Box::new(ident("Color")),
+        member: name.to_string(),
+    };
+    Statement::SwitchStatement {
+        token: token(TokenType::SwitchKeyword),
+        expression: ident("color"),
+        cases: labels
+            .iter()
+            .map(|group| (group.iter().map(|name| variant(name)).collect(), vec![]))
+            .collect(),
+        default: if has_default { Some(vec![]) } else { None },
+    }
+}
+
+#[test]
+fn test_semantic_interface_fully_implemented() {
+    let program = vec![
+        interface("Shape", &["area", "perimeter"]),
+        class("Square", None, &["Shape"], &["area", "perimeter", "scale"]),
+    ];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_interface_method_missing() {
+    let program = vec![interface("Shape", &["area", "perimeter"]), class("Square", None, &["Shape"], &["area"])];
+    let analyzer = analyze(&program);
+
+    assert_eq!(
+        analyzer.get_errors(),
+        &vec![SemanticError::MissingInterfaceMethod {
+            class: "Square".to_string(),
+            interface: "Shape".to_string(),
+            method: "perimeter".to_string(),
+        }]
+    );
+    assert_eq!(
+        analyzer.get_errors()[0].to_string(),
+        "Class 'Square' does not implement method 'perimeter' from interface 'Shape'"
+    );
+}
+
+#[test]
+fn test_semantic_empty_interface() {
+    let program = vec![interface("Marker", &[]), class("Thing", None, &["Marker"], &[])];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_interface_method_from_superclass() {
+    let program = vec![
+        interface("Shape", &["area", "perimeter"]),
+        class("Polygon", None, &[], &["perimeter"]),
+        class("Square", Some("Polygon"), &["Shape"], &["area"]),
+        class("Blob", Some("Polygon"), &["Shape"], &[]),
+    ];
+    let analyzer = analyze(&program);
+
+    assert_eq!(analyzer.get_errors().len(), 1);
+    assert!(matches!(
+        &analyzer.get_errors()[0],
+        SemanticError::MissingInterfaceMethod { class, method, .. } if class == "Blob" && method == "area"
+    ));
+}
+
+#[test]
+fn test_semantic_interface_method_from_mixin() {
+    let program = vec![
+        interface("Walking", &["walk"]),
+        class("Walker", None, &[], &["walk"]),
+        with_mixins(class("Duck", None, &["Walking"], &[]), &["Walker"]),
+        with_mixins(class("Robot", None, &["Walking"], &[]), &["Imported"]),
+    ];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_mixins_define_same_method() {
+    let program = vec![
+        class("Walker", None, &[], &["walk", "rest"]),
+        class("Runner", None, &[], &["walk", "sprint"]),
+        with_mixins(class("Duck", None, &[], &["quack"]), &["Walker", "Runner"]),
+    ];
+    let analyzer = analyze(&program);
+
+    assert_eq!(
+        analyzer.get_errors(),
+        &vec![SemanticError::MixinMethodConflict {
+            class: "Duck".to_string(),
+            method: "walk".to_string(),
+            sources: vec!["Walker".to_string(), "Runner".to_string()],
+        }]
+    );
+    assert_eq!(
+        analyzer.get_errors()[0].to_string(),
+        "Method 'walk' of class 'Duck' is defined by both 'Walker' and 'Runner' without an override"
+    );
+}
+
+#[test]
+fn test_semantic_class_and_mixin_define_same_method() {
+    let program = vec![
+        class("Walker", None, &[], &["walk"]),
+        with_mixins(class("Duck", None, &[], &["walk"]), &["Walker"]),
+    ];
+    let analyzer = analyze(&program);
+
+    assert_eq!(analyzer.get_errors().len(), 1);
+    assert!(matches!(
+        &analyzer.get_errors()[0],
+        SemanticError::MixinMethodConflict { sources, .. } if sources == &["Duck", "Walker"]
+    ));
+}
+
+#[test]
+fn test_semantic_override_resolves_mixin_conflict() {
+    let program = vec![
+        class("Walker", None, &[], &["walk"]),
+        class("Runner", None, &[], &["walk"]),
+        with_mixins(with_overrides(class("Duck", None, &[], &["walk"]), &["walk"]), &["Walker", "Runner"]),
+    ];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_override_of_superclass_method() {
+    let program = vec![
+        class("Animal", None, &[], &["speak"]),
+        class("Dog", Some("Animal"), &[], &[]),
+        with_overrides(class("Puppy", Some("Dog"), &[], &["speak", "play"]), &["speak"]),
+    ];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_override_without_inherited_method() {
+    let program = vec![
+        class("Animal", None, &[], &["speak"]),
+        with_overrides(class("Dog", Some("Animal"), &[], &["fetch"]), &["fetch"]),
+        with_overrides(class("Rock", None, &[], &["speak"]), &["speak"]),
+    ];
+    let analyzer = analyze(&program);
+
+    assert_eq!(
+        analyzer.get_errors(),
+        &vec![
+            SemanticError::NothingToOverride { class: "Dog".to_string(), method: "fetch".to_string() },
+            SemanticError::NothingToOverride { class: "Rock".to_string(), method: "speak".to_string() },
+        ]
+    );
+    assert_eq!(
+        analyzer.get_errors()[0].to_string(),
+        "Method 'fetch' of class 'Dog' is marked 'overrides' but does not override an inherited method"
+    );
+}
+
+#[test]
+fn test_semantic_skips_unknown_declarations() {
+    let program = vec![
+        interface("Shape", &["area"]),
+        class("Imported", Some("External"), &["Shape"], &[]),
+        class("Local", None, &["Printable"], &[]),
+    ];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_checks_default_exported_class() {
+    let program = vec![
+        interface("Shape", &["area"]),
+        Statement::ExportDeclaration {
+            token: token(TokenType::ExportKeyword),
+            specifiers: vec![ExportSpecifier::Default(Box::new(class("Square", None, &["Shape"], &[])))],
+        },
+    ];
+    assert_eq!(analyze(&program).get_errors().len(), 1);
+}
+
+#[test]
+fn test_semantic_exhaustive_enum_switch() {
+    let program = vec![
+        enum_declaration("Color", &["Red", "Green", "Blue"]),
+        function("paint", &[], None, vec![switch_on_color(&[&["Red"], &["Green", "Blue"]], false)]),
+    ];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_enum_switch_with_default() {
+    let program = vec![enum_declaration("Color", &["Red", "Green", "Blue"]), switch_on_color(&[&["Red"]], true)];
+    assert!(analyze(&program).get_errors().is_empty());
+}
+
+#[test]
+fn test_semantic_enum_switch_missing_variant() {
+    let program = vec![
+        enum_declaration("Color", &["Red", "Green", "Blue"]),
+        function(
+            "paint",
+            &[],
+            None,
+            vec![while_loop(ident("running"), vec![switch_on_color(&[&["Red", "Blue"]], false)])],
+        ),
+    ];
+    let analyzer = analyze(&program);
+
+    assert_eq!(
+        analyzer.get_errors(),
+        &vec![SemanticError::NonExhaustiveSwitch {
+            enum_name: "Color".to_string(),
+            missing_variants: vec!["Green".to_string()],
+        }]
+    );
+    assert_eq!(
+        analyzer.get_errors()[0].to_string(),
+        "Switch over enum 'Color' has no default and does not handle Green"
+    );
+}
\ No newline at end of file
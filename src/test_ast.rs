@@ -0,0 +1,87 @@
+// Builders for hand-written ASTs used by the code generator, lint and semantic tests
+
+use crate::ast::{Expression, LiteralValue, Statement, TypeAnnotation};
+use crate::token::{Token, TokenType};
+
+pub fn token(token_type: TokenType) -> Token {
+    Token::new(token_type, 1, 1)
+}
+
+pub fn int(value: i32) -> Expression {
+    Expression::Literal { token: token(TokenType::Int(value)), value: LiteralValue::Int(value) }
+}
+
+pub fn boolean(value: bool) -> Expression {
+    let token_type = if value { TokenType::TrueKeyword } else { TokenType::FalseKeyword };
+    Expression::Literal { token: token(token_type), value: LiteralValue::Bool(value) }
+}
+
+pub fn ident(name: &str) -> Expression {
+    Expression::Identifier { token: token(TokenType::Identifier(name.to_string())), name: name.to_string() }
+}
+
+pub fn binary(left: Expression, operator: TokenType, right: Expression) -> Expression {
+    Expression::BinaryOperation {
+        token: token(operator.clone()),
+        left: Box::new(left),
+        operator,
+        right: Box::new(right),
+    }
+}
+
+pub fn assign(name: &str, value: Expression) -> Expression {
+    Expression::Assignment { token: token(TokenType::Equals), left: Box::new(ident(name)), right: Box::new(value) }
+}
+
+// `name op= value`, with `operator` one of the compound assignment tokens such as `PlusEquals`
+pub fn compound_assign(name: &str, operator: TokenType, value: Expression) -> Expression {
+    Expression::Assignment { token: token(operator), left: Box::new(ident(name)), right: Box::new(value) }
+}
+
+pub fn call(name: &str, arguments: Vec<Expression>) -> Expression {
+    Expression::FunctionCall { token: token(TokenType::LeftParen), callee: Box::new(ident(name)), arguments }
+}
+
+pub fn var(name: &str, type_name: &str, value: Expression) -> Statement {
+    Statement::VariableDeclaration {
+        token: token(TokenType::IntKeyword),
+        name: name.to_string(),
+        type_name: Some(TypeAnnotation::Named(type_name.to_string())),
+        value: Some(value),
+    }
+}
+
+pub fn ret(value: Expression) -> Statement {
+    Statement::ReturnStatement { token: token(TokenType::ReturnKeyword), value: Some(value) }
+}
+
+pub fn if_else(condition: Expression, then_branch: Vec<Statement>, else_branch: Option<Vec<Statement>>) -> Statement {
+    Statement::IfStatement {
+        token: token(TokenType::IfKeyword),
+        condition,
+        then_branch: Box::new(Statement::BlockStatement(then_branch)),
+        else_branch: else_branch.map(|body| Box::new(Statement::BlockStatement(body))),
+    }
+}
+
+pub fn while_loop(condition: Expression, body: Vec<Statement>) -> Statement {
+    Statement::WhileStatement {
+        token: token(TokenType::WhileKeyword),
+        condition,
+        body: Box::new(Statement::BlockStatement(body)),
+    }
+}
+
+pub fn function(name: &str, parameters: &[(&str, &str)], return_type: Option<&str>, body: Vec<Statement>) -> Statement {
+    Statement::FunctionDeclaration {
+        token: token(TokenType::FunctionKeyword),
+        name: name.to_string(),
+        parameters: parameters
+            .iter()
+            .map(|(name, type_name)| (name.to_string(), TypeAnnotation::Named(type_name.to_string())))
+            .collect(),
+        body,
+        return_type: return_type.map(|type_name| TypeAnnotation::Named(type_name.to_string())),
+        cleanup: None,
+    }
+}
\ No newline at end of file
@@ -20,6 +20,7 @@ pub enum TokenType {
     WhileKeyword,
     ForKeyword,
     OfKeyword,
+    InKeyword,
     SwitchKeyword,
     CaseKeyword,
     BreakKeyword,
@@ -42,6 +43,18 @@ pub enum TokenType {
     DefaultKeyword,
     NewKeyword,
     ThisKeyword,
+    SuperKeyword,
+    CloneKeyword,
+    FinallyKeyword,
+    AsKeyword,
+    OverridesKeyword,
+    DeferKeyword,
+    CancelKeyword,
+    StructKeyword,
+    UnionKeyword,
+    WithKeyword,
+    DeleteKeyword,
+    FreezeKeyword,
 
     // Operators
     Plus,
@@ -66,11 +79,13 @@ pub enum TokenType {
     LogicalAnd,
     LogicalOr,
     LogicalNot,
+    Pipe,
 
     // Punctuation
     Semicolon,
     Comma,
     Colon,
+    QuestionMark,
     Dot,
     LeftParen,
     RightParen,
@@ -90,6 +105,9 @@ pub struct Token {
     pub token_type: TokenType,
     pub line: usize,
     pub column: usize,
+    // Byte range of the token in the source it was lexed from
+    pub start_offset: usize,
+    pub end_offset: usize,
 }
 
 impl Token {
@@ -98,6 +116,14 @@ impl Token {
             token_type,
             line,
             column,
+            start_offset: 0,
+            end_offset: 0,
         }
     }
+
+    // Raw source text of the token, as written. For strings this keeps the
+    // quotes and escapes, unlike the String payload.
+    pub fn lexeme<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start_offset..self.end_offset]
+    }
 }
\ No newline at end of file